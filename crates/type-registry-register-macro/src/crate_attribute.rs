@@ -1,7 +1,8 @@
 use proc_macro2::TokenStream;
 use quote::ToTokens;
-use syn::{Path, Token};
+use syn::{Attribute, Path, Token};
 use syn::parse::{Parse, ParseStream};
+use crate::type_registry_attribute::TypeRegistryAttribute;
 
 pub struct CrateAttribute {
     keyword: Token![crate],
@@ -13,6 +14,27 @@ impl CrateAttribute {
     pub fn into_path(self) -> Path {
         self.path
     }
+
+    /// Removes any `#[type_registry(...)]` attributes from the given attributes, returning the
+    /// crate path specified by `#[type_registry(crate = ...)]` (or the default path if there is
+    /// none). Any other `type_registry` attributes are an error.
+    pub fn extract(attrs: &mut Vec<Attribute>) -> syn::Result<Path> {
+        let mut crate_ = None;
+
+        for attr in TypeRegistryAttribute::extract(attrs)? {
+            match attr {
+                TypeRegistryAttribute::Crate(attr) if crate_.is_none() => crate_ = Some(attr.into_path()),
+                TypeRegistryAttribute::Crate(attr) => return Err(syn::Error::new_spanned(attr, "duplicate crate path")),
+                other => return Err(other.unexpected())
+            }
+        }
+
+        Ok(crate_.unwrap_or_else(Self::default_crate))
+    }
+
+    pub fn default_crate() -> Path {
+        syn::parse_str("::type_registry").expect("path is well-formed")
+    }
 }
 
 impl Parse for CrateAttribute {
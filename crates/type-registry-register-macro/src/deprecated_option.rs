@@ -0,0 +1,117 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{Ident, LitStr, Path, Token};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::token::Paren;
+
+/// The `deprecated` or `deprecated(since = "...", note = "...")` registration option, where both
+/// arguments are optional.
+#[derive(Clone)]
+pub(crate) struct DeprecatedOption {
+    keyword: Ident,
+    paren: Option<Paren>,
+    args: Punctuated<DeprecatedArg, Token![,]>
+}
+
+impl DeprecatedOption {
+    pub const KEYWORD: &'static str = "deprecated";
+
+    pub fn keyword(&self) -> &Ident {
+        &self.keyword
+    }
+
+    /// The `Deprecation` described by the option.
+    pub fn deprecation(&self, crate_: &Path) -> TokenStream {
+        let arg = |name: &str| match self.args.iter().find(|arg| arg.name == name) {
+            Some(arg) => {
+                let value = &arg.value;
+                quote!(::core::option::Option::Some(#value))
+            },
+            None => quote!(::core::option::Option::None)
+        };
+
+        let since = arg("since");
+        let note = arg("note");
+        quote!(#crate_::Deprecation::new(#since, #note))
+    }
+}
+
+impl Parse for DeprecatedOption {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let keyword: Ident = Parse::parse(input)?;
+        if keyword != Self::KEYWORD {
+            return Err(syn::Error::new_spanned(keyword, "expected `deprecated`"))
+        }
+
+        if !input.peek(Paren) {
+            return Ok(
+                Self {
+                    keyword,
+                    paren: None,
+                    args: Punctuated::new()
+                }
+            )
+        }
+
+        let content;
+        let paren = syn::parenthesized!(content in input);
+        let args: Punctuated<DeprecatedArg, Token![,]> = Punctuated::parse_terminated(&content)?;
+
+        for (index, arg) in args.iter().enumerate() {
+            if args.iter().take(index).any(|other| other.name == arg.name) {
+                return Err(syn::Error::new_spanned(&arg.name, "duplicate argument"))
+            }
+        }
+
+        Ok(
+            Self {
+                keyword,
+                paren: Some(paren),
+                args
+            }
+        )
+    }
+}
+
+impl ToTokens for DeprecatedOption {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.keyword.to_tokens(tokens);
+        if let Some(paren) = &self.paren {
+            paren.surround(tokens, |tokens| self.args.to_tokens(tokens));
+        }
+    }
+}
+
+/// An argument of the [deprecated option](DeprecatedOption), `since = "..."` or `note = "..."`.
+#[derive(Clone)]
+struct DeprecatedArg {
+    name: Ident,
+    equals: Token![=],
+    value: LitStr
+}
+
+impl Parse for DeprecatedArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: Ident = Parse::parse(input)?;
+        if name != "since" && name != "note" {
+            return Err(syn::Error::new_spanned(name, "unknown argument, expected `since` or `note`"))
+        }
+
+        Ok(
+            Self {
+                name,
+                equals: Parse::parse(input)?,
+                value: Parse::parse(input)?
+            }
+        )
+    }
+}
+
+impl ToTokens for DeprecatedArg {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.name.to_tokens(tokens);
+        self.equals.to_tokens(tokens);
+        self.value.to_tokens(tokens);
+    }
+}
@@ -0,0 +1,27 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ItemFn, Path};
+use crate::fn_pointer::fn_pointer_type;
+
+/// Generates the marker type which stands in for a registered function, along with its
+/// implementation of `RegisteredFn`. The marker is a braced struct with the same name as the
+/// function, so it occupies only the type namespace and doesn't clash with the function itself.
+pub(crate) fn fn_marker(crate_: &Path, item: &ItemFn) -> TokenStream {
+    let vis = &item.vis;
+    let ident = &item.sig.ident;
+    let fn_pointer_type = fn_pointer_type(&item.sig);
+
+    quote!(
+        #[doc(hidden)]
+        #[allow(non_camel_case_types)]
+        #vis struct #ident {}
+
+        impl #crate_::RegisteredFn for #ident {
+            type Fn = #fn_pointer_type;
+
+            const FN: Self::Fn = #ident;
+
+            const NAME: &'static str = ::core::stringify!(#ident);
+        }
+    )
+}
@@ -0,0 +1,35 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{FnArg, Signature};
+
+/// Checks that a function with the given signature can be referred to by a plain function
+/// pointer.
+pub(crate) fn check_signature(sig: &Signature, what: &str) -> syn::Result<()> {
+    let generics = &sig.generics;
+    if !generics.params.is_empty() || generics.where_clause.is_some() {
+        return Err(syn::Error::new_spanned(generics, format!("generic {what} aren't supported")))
+    }
+    if let Some(asyncness) = &sig.asyncness {
+        return Err(syn::Error::new_spanned(asyncness, format!("async {what} aren't supported")))
+    }
+    if let Some(variadic) = &sig.variadic {
+        return Err(syn::Error::new_spanned(variadic, format!("variadic {what} aren't supported")))
+    }
+    if let Some(receiver) = sig.receiver() {
+        return Err(syn::Error::new_spanned(receiver, format!("{what} with receivers aren't supported")))
+    }
+    Ok(())
+}
+
+/// The function pointer type for a function with the given (checked) signature.
+pub(crate) fn fn_pointer_type(sig: &Signature) -> TokenStream {
+    let unsafety = &sig.unsafety;
+    let abi = &sig.abi;
+    let output = &sig.output;
+    let inputs = sig.inputs.iter().map(|arg| match arg {
+        FnArg::Typed(arg) => &arg.ty,
+        FnArg::Receiver(_) => unreachable!("receivers are rejected by check_signature")
+    });
+
+    quote!(#unsafety #abi fn(#(#inputs),*) #output)
+}
@@ -0,0 +1,66 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{parenthesized, Ident, Token, Type};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::token::Paren;
+
+/// The `instances(...)` argument to the register attribute, listing the concrete instantiations
+/// of a generic type which should be registered.
+pub(crate) struct InstancesArg {
+    keyword: Ident,
+    paren: Paren,
+    types: Punctuated<Type, Token![,]>
+}
+
+impl InstancesArg {
+    pub const KEYWORD: &'static str = "instances";
+
+    /// Whether the input stream starts with an `instances(...)` argument.
+    pub fn peek(input: ParseStream) -> bool {
+        let fork = input.fork();
+        match fork.parse::<Ident>() {
+            Ok(ident) => ident == Self::KEYWORD && fork.peek(Paren),
+            Err(_) => false
+        }
+    }
+
+    pub fn keyword(&self) -> &Ident {
+        &self.keyword
+    }
+
+    pub fn types(&self) -> impl Iterator<Item=&Type> {
+        self.types.iter()
+    }
+}
+
+impl Parse for InstancesArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let keyword: Ident = Parse::parse(input)?;
+        if keyword != Self::KEYWORD {
+            return Err(syn::Error::new_spanned(keyword, "expected `instances`"))
+        }
+        let content;
+        let paren = parenthesized!(content in input);
+        let types = Punctuated::parse_terminated(&content)?;
+
+        if types.is_empty() {
+            return Err(syn::Error::new(paren.span.join(), "expected at least one instance type"))
+        }
+
+        Ok(
+            Self {
+                keyword,
+                paren,
+                types
+            }
+        )
+    }
+}
+
+impl ToTokens for InstancesArg {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.keyword.to_tokens(tokens);
+        self.paren.surround(tokens, |tokens| self.types.to_tokens(tokens));
+    }
+}
@@ -1,37 +1,108 @@
 mod register_input;
 mod register_attr;
+mod register_arg;
+mod registry_arg;
+mod registry_arg_item;
+mod registration_option;
+mod registration_options;
+mod deprecated_option;
+mod version_syntax;
+mod instances_arg;
+mod registered_impl;
+mod fn_marker;
+mod fn_pointer;
+mod value_marker;
 mod crate_attribute;
+mod type_registry_attribute;
+mod register_all;
+mod nest_paths;
+mod register_variants;
+mod type_info_attribute;
+mod type_info_input;
+mod type_info_impls;
+mod registry_trait;
+mod registry_trait_attr;
+mod registry_attribute;
+mod registry_input;
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::parse_macro_input;
+use syn::{parse_macro_input, ItemEnum, ItemMod, ItemTrait, Type};
+use crate::crate_attribute::CrateAttribute;
 use crate::register_attr::RegisterAttr;
 use crate::register_input::RegisterInput;
+use crate::register_all::{check_no_unique_options, register_all as register_all_impl};
+use crate::register_variants::register_variants as register_variants_impl;
+use crate::registered_impl::{registered_impl, stable_type_name};
+use crate::registry_trait::registry_trait as registry_trait_impl;
+use crate::registry_trait_attr::RegistryTraitAttr;
+use crate::registry_input::RegistryInput;
+use crate::type_info_input::TypeInfoInput;
+use crate::type_info_impls::type_info_impls;
 
 /// Implements the Registered trait for a type and registers the type with the given
-/// type-registry. Can be applied to structs, enums and unions.
+/// type-registry. Can be applied to structs, enums, unions and trait implementations (in which
+/// case the implementation's self type is registered). Can also be applied to functions, in which
+/// case a marker type with the same name as the function is generated and registered instead
+/// (see `type_registry::FnInfo`). Similarly for statics and consts, whose values can then be
+/// accessed through the registry (see `type_registry::ValueRegistry`). Generic types must list
+/// the concrete instantiations to register with `instances(...)`.
+///
+/// Options which customise the registration can be given as `name = value` arguments, either
+/// after all registries (applying to each) or in the parentheses following a registry (applying
+/// to that registry only). The available options are:
+/// - `key = "..."`: the key to register under, which can be looked up with
+///   `RegistryExt::get`.
+/// - `aliases = ["...", ...]`: alternative keys to register under (e.g. former keys), which can
+///   also be looked up with `RegistryExt::get` (or `RegistryExt::lookup`, which reports when an
+///   alias was used).
+/// - `id = ...`: an explicit numeric ID (a `u32`) which is stable across changes to the set of
+///   registered types, which can be looked up with `RegistrationId::from_explicit`.
+/// - `stable_name = "..."`: the name to record for the type, which can be looked up with
+///   `RegistryExt::find_by_stable_name`. Defaults to the module path followed by the type as
+///   written, e.g. `my_crate::my_module::MyType`, which (unlike `std::any::type_name`) doesn't
+///   depend on the compiler version. Trait implementations, whose self type may be defined in
+///   another module or crate, have no stable name unless one is given.
+/// - `priority = ...`: the priority (an `i32`, default `0`) of the registration over others with
+///   the same key. Keyed lookups resolve to the registration with the highest priority, and the
+///   others are overridden (see `RegistryExt::iter_all`).
+/// - `weak`: marks the registration as a fallback, which is overridden by any non-weak
+///   registration with the same key (whatever their priorities).
+/// - `after = [...]`/`before = [...]`: types (registered to the same registry) which the type must
+///   be ordered after/before by `RegistryExt::iter_ordered`.
+/// - `deprecated` or `deprecated(since = "...", note = "...")`: marks the registration as
+///   deprecated, which is reported to `Registry::on_deprecated_use` when it's looked up by key or
+///   name.
+/// - `version = "..."`: the version of the registered type, e.g. of a plugin, as
+///   `MAJOR.MINOR.PATCH`.
+/// - `requires = "..."`: a requirement on the host version, e.g. `">=1.4"`. Types whose
+///   requirement isn't met are excluded by `RegistryExt::compatible_with`. Both versions and
+///   requirements are checked at compile time.
+/// - `tags = ["...", ...]`: tags to register with, which can be looked up with
+///   `RegistryExt::with_tag`.
 ///
 /// ```
 /// use std::fmt::Debug;
-/// use type_registry::Registry;
+/// use type_registry::{Registry, TypeInfoFor};
 /// use type_registry_register_macro::register;
 ///
 /// struct MyTypeInfo {
 ///     very_important_info: usize
 /// }
 ///
-/// impl MyTypeInfo {
-///     // The macro assumes that the type-info type has a const, no-argument `new` fn like this,
-///     // which is generic on the type being registered (the generic parameter can have bounds,
-///     // like the implicit `Sized` bound here.
-///     pub const fn new<T>() -> Self {
-///         Self {
-///             very_important_info: std::mem::size_of::<T>()
-///         }
-///     }
+/// // By default, the macro initialises the type-info from its `TypeInfoFor` implementation for
+/// // the type being registered (the generic parameter can have bounds, like the implicit `Sized`
+/// // bound here).
+/// impl<T> TypeInfoFor<T> for MyTypeInfo {
+///     const TYPE_INFO: Self = Self {
+///         very_important_info: std::mem::size_of::<T>()
+///     };
+/// }
 ///
+/// impl MyTypeInfo {
 ///     // If this is not the case, or custom initialisation is needed for some other reason, the
-///     // initialisation can be overridden.
+///     // initialisation can be overridden by following the registry with the initialisation
+///     // expression in parentheses.
 ///     pub const fn new_unsized(number: usize) -> Self {
 ///         Self {
 ///             very_important_info: number
@@ -49,7 +120,7 @@ use crate::register_input::RegisterInput;
 ///     }
 /// }
 ///
-/// #[register(MyRegistry, MyTypeInfo::new_unsized(42))]
+/// #[register(MyRegistry(MyTypeInfo::new_unsized(42)))]
 /// struct MyStruct([u8]);
 ///
 /// #[register(MyRegistry)]
@@ -63,45 +134,408 @@ use crate::register_input::RegisterInput;
 /// // If the macro has been exported from a non-standard path, use this attribute to customise it
 /// #[type_registry(crate = reexport::type_registry_reexported)]
 /// union MyUnion { u8: u8, u16: u16 }
+///
+/// // Generic types can't be registered directly, but concrete instantiations of them can be
+/// // listed. Each instantiation is registered (and gets its own type-info) separately.
+/// #[register(MyRegistry, instances(MyGeneric<u8>, MyGeneric<String>))]
+/// struct MyGeneric<T>(T);
+///
+/// trait MyTrait {}
+///
+/// // Registering a trait implementation registers its self type, which means types from other
+/// // crates can be registered too.
+/// #[register(MyRegistry)]
+/// impl MyTrait for String {}
+///
+/// struct MyOtherRegistry;
+///
+/// impl Registry for MyOtherRegistry {
+///     type TypeInfo = ();
+///
+///     fn name() -> &'static str {
+///         "My Other Registry"
+///     }
+/// }
+///
+/// // A type can be registered with several registries at once, each with its own (optional)
+/// // initialisation expression and options
+/// #[register(MyRegistry(MyTypeInfo::new_unsized(0), key = "multiple"), MyOtherRegistry(()))]
+/// struct MyMultiplyRegisteredStruct;
+/// ```
+///
+/// Initialisation expressions used to be given as a separate argument after the registry, which is
+/// now an error, whether the expression is a constant:
+///
+/// ```compile_fail
+/// use type_registry::Registry;
+/// use type_registry_register_macro::register;
+///
+/// struct Sizes;
+///
+/// impl Registry for Sizes {
+///     type TypeInfo = usize;
+///
+///     fn name() -> &'static str {
+///         "Sizes"
+///     }
+/// }
+///
+/// const SIZE: usize = 42;
+///
+/// #[register(Sizes, SIZE)]
+/// struct MyStruct;
+/// ```
+///
+/// or a call:
+///
+/// ```compile_fail
+/// use type_registry::Registry;
+/// use type_registry_register_macro::register;
+///
+/// struct Sizes;
+///
+/// impl Registry for Sizes {
+///     type TypeInfo = usize;
+///
+///     fn name() -> &'static str {
+///         "Sizes"
+///     }
+/// }
+///
+/// const fn size() -> usize {
+///     42
+/// }
+///
+/// #[register(Sizes, size())]
+/// struct MyStruct;
 /// ```
 #[proc_macro_attribute]
 pub fn register(attr: TokenStream, input: TokenStream) -> TokenStream {
     let mut input = parse_macro_input!(input as RegisterInput);
 
+    let crate_ = match input.crate_() {
+        Ok(path) => path,
+        Err(error) => return error.into_compile_error().into()
+    };
+    let registered_type = input.registered_type();
+
+    let attr = parse_macro_input!(attr as RegisterAttr);
+
     let generics = input.generics();
     let generic_tokens = quote! { #generics };
-    if !generic_tokens.is_empty() {
-        return syn::Error::new_spanned(
+    let registered_types: Vec<Type> = match (attr.instances(), generic_tokens.is_empty()) {
+        (None, true) => vec![registered_type],
+        (None, false) => return syn::Error::new_spanned(
             generic_tokens,
-            "registration does not support generics"
+            "registration of generic types requires `instances(...)`"
+        ).into_compile_error().into(),
+        (Some(instances), false) => instances.types().cloned().collect(),
+        (Some(instances), true) => return syn::Error::new_spanned(
+            instances.keyword(),
+            "`instances(...)` can only be used with generic types"
         ).into_compile_error().into()
+    };
+
+    if registered_types.len() > 1 {
+        if let Err(error) = check_no_unique_options(&attr) {
+            return error.into_compile_error().into()
+        }
     }
 
-    let crate_ = match input.crate_() {
+    // An implementation's self type may be imported or from another crate, so the module path
+    // can't be used as its default stable name
+    let item_name = |registered: &Type| match &input {
+        RegisterInput::Impl(_) => None,
+        _ => Some(stable_type_name(registered))
+    };
+
+    let registered_impls = attr.registries().flat_map(
+        |registry| registered_types.iter().map(
+            |registered| registered_impl(
+                &crate_,
+                registry.registry(),
+                registered,
+                item_name(registered).as_deref(),
+                &registry.init_type_info_expr(registered, &crate_),
+                &attr.options().overridden_by(registry.options())
+            )
+        )
+    ).collect::<Vec<_>>();
+
+    let supporting_items = input.supporting_items(&crate_);
+
+    quote!(
+        #input
+
+        #supporting_items
+
+        #(#registered_impls)*
+    ).into()
+}
+
+/// Registers every struct, enum and union in an inline module, as if each had been annotated
+/// with [register](macro@register) using the same arguments (other than `instances(...)`, which
+/// isn't supported). Nested modules are not searched.
+///
+/// Individual items can opt out with `#[type_registry(skip)]`, which is required for generic
+/// types, can override the type-info initialisation expression with
+/// `#[type_registry(init = ...)]`, and can set registration options with e.g.
+/// `#[type_registry(key = "...")]`. The module itself can use `#[type_registry(crate = ...)]`
+/// as for [register](macro@register).
+///
+/// As with any attribute, paths in the arguments (including `self::` and `super::` paths) are
+/// relative to the module's parent, not the module itself.
+///
+/// ```
+/// use type_registry::{register_all, Registry, RegistryExt};
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = usize)]
+/// struct SizeRegistry;
+///
+/// #[register_all(self::SizeRegistry(0))]
+/// mod plugins {
+///     pub struct Unit;
+///
+///     #[type_registry(init = 42)]
+///     pub enum Answer {}
+///
+///     #[type_registry(skip)]
+///     pub struct Unregistered;
+/// }
+///
+/// fn main() {
+///     let mut sizes: Vec<usize> = SizeRegistry::iter()
+///         .map(|(_id, entry)| *entry.type_info())
+///         .collect();
+///     sizes.sort();
+///     assert_eq!(sizes, [0, 42]);
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn register_all(attr: TokenStream, input: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(input as ItemMod);
+    let attr = parse_macro_input!(attr as RegisterAttr);
+
+    match register_all_impl(attr, item) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.into_compile_error().into()
+    }
+}
+
+/// Registers each variant of an enum as a separate entry, rather than the enum as a whole. Takes
+/// the same arguments as [register](macro@register) (other than `instances(...)`, which isn't
+/// supported).
+///
+/// Each variant is represented by a generated marker type implementing `RegisteredVariant`,
+/// which gives the variant's name, discriminant and field count to its type-info (see
+/// `type_registry::VariantInfo`). Individual variants can override the type-info initialisation
+/// expression with `#[type_registry(init = ...)]`, and set registration options with e.g.
+/// `#[type_registry(key = "...")]`. The enum implements `RegisteredVariants` for
+/// each registry, mapping a value to the registration of its variant.
+///
+/// ```
+/// use type_registry::{register_variants, Registry, RegistryExt, RegistrationId};
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = &'static str)]
+/// struct HelpRegistry;
+///
+/// #[register_variants(HelpRegistry("no help available"))]
+/// enum Command {
+///     #[type_registry(init = "starts the engine", key = "start")]
+///     Start,
+///     Stop(bool)
+/// }
+///
+/// fn main() {
+///     assert_eq!(HelpRegistry::get("start").map(|entry| *entry.type_info()), Some("starts the engine"));
+///     let help = |command| *RegistrationId::<HelpRegistry>::of_variant(&command).metadata();
+///     assert_eq!(help(Command::Start), "starts the engine");
+///     assert_eq!(help(Command::Stop(true)), "no help available");
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn register_variants(attr: TokenStream, input: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(input as ItemEnum);
+    let attr = parse_macro_input!(attr as RegisterAttr);
+
+    match register_variants_impl(attr, item) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.into_compile_error().into()
+    }
+}
+
+/// Derives `TypeInfoFor<T>` for a type-info struct, for all `T` implementing the trait given by
+/// the `#[type_info(trait = ...)]` attribute, along with an equivalent const `new::<T>()` fn. Each
+/// field of the struct must be a function pointer, and is initialised from the associated function
+/// with the same name in the trait.
+///
+/// ```
+/// use type_registry::{register, Registered, Registry, RegistryExt, TypeInfo};
+///
+/// trait StringAnalyser: Registered<StringAnalyserRegistry> {
+///     fn analyse(string: &str) -> Option<usize>;
+/// }
+///
+/// #[derive(TypeInfo)]
+/// #[type_info(trait = StringAnalyser)]
+/// struct StringAnalyserTypeInfo {
+///     // Initialised as `analyse: T::analyse` in `new::<T: StringAnalyser>()`
+///     analyse: fn(&str) -> Option<usize>
+/// }
+///
+/// struct StringAnalyserRegistry;
+///
+/// impl Registry for StringAnalyserRegistry {
+///     type TypeInfo = StringAnalyserTypeInfo;
+///
+///     fn name() -> &'static str {
+///         "String Analysers"
+///     }
+/// }
+///
+/// #[register(StringAnalyserRegistry)]
+/// struct LenAnalyser;
+///
+/// impl StringAnalyser for LenAnalyser {
+///     fn analyse(string: &str) -> Option<usize> {
+///         Some(string.len())
+///     }
+/// }
+///
+/// fn main() {
+///     for (_id, entry) in StringAnalyserRegistry::iter() {
+///         assert_eq!((entry.type_info().analyse)("I'm 22 characters long"), Some(22))
+///     }
+/// }
+/// ```
+#[proc_macro_derive(TypeInfo, attributes(type_info, type_registry))]
+pub fn derive_type_info(input: TokenStream) -> TokenStream {
+    let type_info_input = parse_macro_input!(input as TypeInfoInput);
+    let input = type_info_input.input();
+
+    let crate_ = match CrateAttribute::extract(&mut input.attrs.clone()) {
         Ok(path) => path,
         Err(error) => return error.into_compile_error().into()
     };
-    let ident = input.ident();
 
-    let attr = parse_macro_input!(attr as RegisterAttr);
+    type_info_impls(
+        &crate_,
+        &input.vis,
+        &input.ident,
+        &input.generics,
+        type_info_input.trait_(),
+        type_info_input.field_idents()
+    ).into()
+}
 
-    let (
-        registry,
-        init_type_info_expr
-    ) = attr.into_parts(ident, &crate_);
+/// Generates a complete registry for the implementors of a trait: a registry type, a type-info
+/// struct holding a function pointer for each of the trait's associated functions (along with
+/// its `TypeInfoFor<T>` implementation, as for the [TypeInfo](derive@TypeInfo) derive), and a
+/// `Registered` supertrait bound on the trait so that all implementors must be registered.
+///
+/// The generated items are named `<Trait>Registry` and `<Trait>TypeInfo` by default, which can be
+/// overridden with the `registry` and `type_info` arguments. The registry's name defaults to the
+/// trait's name, and can be overridden with the `name` argument. The registry also gets a
+/// `type_infos()` fn which iterates over the type-info of its registered types directly.
+///
+/// ```
+/// use type_registry::{register, registry_trait};
+///
+/// #[registry_trait(name = "String Analysers")]
+/// trait StringAnalyser {
+///     fn analyse(string: &str) -> Option<usize>;
+/// }
+///
+/// #[register(StringAnalyserRegistry)]
+/// struct LenAnalyser;
+///
+/// impl StringAnalyser for LenAnalyser {
+///     fn analyse(string: &str) -> Option<usize> {
+///         Some(string.len())
+///     }
+/// }
+///
+/// fn main() {
+///     for type_info in StringAnalyserRegistry::type_infos() {
+///         assert_eq!((type_info.analyse)("I'm 22 characters long"), Some(22))
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn registry_trait(attr: TokenStream, input: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(input as ItemTrait);
+    let attr = parse_macro_input!(attr as RegistryTraitAttr);
+
+    match registry_trait_impl(attr, item) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.into_compile_error().into()
+    }
+}
+
+/// Derives the Registry trait, with the type-info given by the `type_info` argument of the
+/// `#[registry(...)]` attribute. The registry's name can be given with the `name` argument, and
+/// defaults to the name of the type. A type of key to look entries up by also implements
+/// `KeyedRegistry` when given with the `key` argument. A function to call when a deprecated entry
+/// is looked up (see `Registry::on_deprecated_use`) can be given with the `on_deprecated_use`
+/// argument.
+///
+/// ```
+/// use type_registry::{register, Registry, RegistryExt};
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = (), name = "My Registry")]
+/// struct MyRegistry;
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = ())]
+/// struct MyUnnamedRegistry;
+///
+/// #[register(MyRegistry, MyUnnamedRegistry)]
+/// struct MyStruct;
+///
+/// fn main() {
+///     assert_eq!(MyRegistry::name(), "My Registry");
+///     assert_eq!(MyUnnamedRegistry::name(), "MyUnnamedRegistry");
+///     assert_eq!(MyRegistry::iter().count(), 1);
+/// }
+/// ```
+#[proc_macro_derive(Registry, attributes(registry, type_registry))]
+pub fn derive_registry(input: TokenStream) -> TokenStream {
+    let registry_input = parse_macro_input!(input as RegistryInput);
+    let input = registry_input.input();
+    let crate_ = registry_input.crate_();
+
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let type_info = registry_input.attr().type_info();
+    let name = registry_input.attr().name(ident);
+    let keyed_registry = registry_input.attr().key().map(|key| quote!(
+        impl #impl_generics #crate_::KeyedRegistry for #ident #type_generics #where_clause {
+            type Key = #key;
+        }
+    ));
+    let on_deprecated_use = registry_input.attr().on_deprecated_use().map(|on_deprecated_use| quote!(
+        fn on_deprecated_use(
+            entry: #crate_::RegistryEntry<Self>,
+            deprecation: &'static #crate_::Deprecation
+        ) {
+            (#on_deprecated_use)(entry, deprecation)
+        }
+    ));
 
     quote!(
-        #input
-        
-        unsafe impl #crate_::Registered<#registry> for #ident {
-            fn register() -> #crate_::Registration<#registry, Self> {
-                 #crate_::registration!(#registry, #ident)
-            }
+        impl #impl_generics #crate_::Registry for #ident #type_generics #where_clause {
+            type TypeInfo = #type_info;
 
-            fn type_info() -> &'static <#registry as #crate_::Registry>::TypeInfo {
-                 static TYPE_INFO: <#registry as #crate_::Registry>::TypeInfo = #init_type_info_expr;
-                 &TYPE_INFO
+            fn name() -> &'static str {
+                #name
             }
+
+            #on_deprecated_use
         }
+
+        #keyed_registry
     ).into()
 }
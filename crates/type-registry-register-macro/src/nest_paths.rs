@@ -0,0 +1,48 @@
+use proc_macro2::Ident;
+use syn::{ExprPath, Path, PathSegment, TypePath};
+use syn::punctuated::Punctuated;
+use syn::visit_mut::{self, VisitMut};
+
+/// Rewrites `self::` and `super::` paths, written relative to a module, so that they resolve from
+/// a module nested two levels below it, e.g. `super::MyRegistry` becomes
+/// `super::super::super::MyRegistry`. Paths within macro invocations aren't rewritten.
+pub(crate) struct NestPaths;
+
+impl VisitMut for NestPaths {
+    fn visit_path_mut(&mut self, path: &mut Path) {
+        visit_mut::visit_path_mut(self, path);
+
+        if path.leading_colon.is_some() || path.segments.len() < 2 { return }
+        let first = &path.segments[0].ident;
+        let skip = if first == "self" {
+            1
+        } else if first == "super" {
+            0
+        } else {
+            return
+        };
+
+        let span = first.span();
+        let mut segments: Punctuated<PathSegment, _> = Punctuated::new();
+        segments.push(PathSegment::from(Ident::new("super", span)));
+        segments.push(PathSegment::from(Ident::new("super", span)));
+        segments.extend(path.segments.iter().skip(skip).cloned());
+        path.segments = segments;
+    }
+
+    fn visit_type_path_mut(&mut self, type_path: &mut TypePath) {
+        let len = type_path.path.segments.len();
+        visit_mut::visit_type_path_mut(self, type_path);
+        if let Some(qself) = &mut type_path.qself {
+            qself.position += type_path.path.segments.len() - len;
+        }
+    }
+
+    fn visit_expr_path_mut(&mut self, expr_path: &mut ExprPath) {
+        let len = expr_path.path.segments.len();
+        visit_mut::visit_expr_path_mut(self, expr_path);
+        if let Some(qself) = &mut expr_path.qself {
+            qself.position += expr_path.path.segments.len() - len;
+        }
+    }
+}
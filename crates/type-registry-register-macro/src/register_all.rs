@@ -0,0 +1,141 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Expr, Item, ItemMod, parse_quote, Path, Type};
+use syn::visit_mut::VisitMut;
+use crate::crate_attribute::CrateAttribute;
+use crate::nest_paths::NestPaths;
+use crate::register_attr::RegisterAttr;
+use crate::registered_impl::registered_impl;
+use crate::registration_options::RegistrationOptions;
+use crate::registry_arg::RegistryArg;
+use crate::type_registry_attribute::TypeRegistryAttribute;
+
+/// Registers every struct, enum and union in an inline module.
+///
+/// The registries, crate path and type-info initialisation expressions in the attribute are
+/// written relative to the module's parent, whereas the registered types may be private to the
+/// module. So the former are aliased in a hidden child module (which glob-imports the parent, and
+/// in which their `self::` and `super::` paths are rewritten to resolve from it) and the
+/// implementations are generated within the module, referring to the aliases.
+pub(crate) fn register_all(attr: RegisterAttr, mut item: ItemMod) -> syn::Result<TokenStream> {
+    if let Some(instances) = attr.instances() {
+        return Err(syn::Error::new_spanned(instances, "`instances(...)` is not supported by register_all"))
+    }
+
+    check_no_unique_options(&attr)?;
+    check_no_type_refs(&attr)?;
+
+    let mut crate_ = CrateAttribute::extract(&mut item.attrs)?;
+    NestPaths.visit_path_mut(&mut crate_);
+
+    let Some((_, items)) = &mut item.content else {
+        return Err(syn::Error::new_spanned(item, "register_all requires an inline module"))
+    };
+
+    let scope = format_ident!("__type_registry_register_all");
+    let scoped_crate: Path = parse_quote!(#scope::type_registry);
+    let mut scope_items = vec![quote!(pub(in super) use #crate_ as type_registry;)];
+
+    let mut registries: Vec<(Type, Option<Expr>, RegistrationOptions)> = Vec::new();
+    for (index, registry) in attr.registries().enumerate() {
+        let registry_alias = format_ident!("Registry{index}");
+        let mut registry_type = registry.registry().clone();
+        NestPaths.visit_type_mut(&mut registry_type);
+        scope_items.push(quote!(pub(in super) type #registry_alias = #registry_type;));
+
+        let init_type_info_expr = registry.explicit_init_type_info_expr().map(|expr| {
+            let mut expr = expr.clone();
+            NestPaths.visit_expr_mut(&mut expr);
+            let init_alias = format_ident!("INIT_TYPE_INFO_{index}");
+            scope_items.push(quote!(
+                pub(in super) const #init_alias: <#registry_alias as type_registry::Registry>::TypeInfo = #expr;
+            ));
+            parse_quote!(#scope::#init_alias)
+        });
+
+        let options = attr.options().overridden_by(registry.options());
+        registries.push((parse_quote!(#scope::#registry_alias), init_type_info_expr, options));
+    }
+
+    let mut registered_impls = Vec::new();
+    for module_item in items.iter_mut() {
+        let (attrs, ident, generics) = match module_item {
+            Item::Struct(item) => (&mut item.attrs, &item.ident, &item.generics),
+            Item::Enum(item) => (&mut item.attrs, &item.ident, &item.generics),
+            Item::Union(item) => (&mut item.attrs, &item.ident, &item.generics),
+            _ => continue
+        };
+
+        let mut skip = false;
+        let mut item_init_type_info_expr = None;
+        let mut item_options = Vec::new();
+        for attr in TypeRegistryAttribute::extract(attrs)? {
+            match attr {
+                TypeRegistryAttribute::Skip(_) if !skip => skip = true,
+                TypeRegistryAttribute::Init { expr, .. } if item_init_type_info_expr.is_none() => item_init_type_info_expr = Some(expr),
+                TypeRegistryAttribute::Option(option) => item_options.push(option),
+                other => return Err(other.unexpected())
+            }
+        }
+        let item_options = RegistrationOptions::collect(&item_options)?;
+
+        if skip { continue }
+
+        if !generics.params.is_empty() {
+            return Err(syn::Error::new_spanned(
+                generics,
+                "generic types can't be registered by register_all, use `#[type_registry(skip)]` and register instances with `#[register(...)]`"
+            ))
+        }
+
+        let registered: Type = parse_quote!(#ident);
+        for (registry, registry_init_type_info_expr, options) in &registries {
+            let init_type_info_expr = item_init_type_info_expr.clone()
+                .or_else(|| registry_init_type_info_expr.clone())
+                .unwrap_or_else(|| RegistryArg::default_init_type_info_expr(registry, &registered, &scoped_crate));
+
+            let options = options.overridden_by(&item_options);
+            registered_impls.push(registered_impl(&scoped_crate, registry, &registered, Some(&ident.to_string()), &init_type_info_expr, &options));
+        }
+    }
+
+    items.push(Item::Verbatim(quote!(
+        #[doc(hidden)]
+        mod #scope {
+            #[allow(unused_imports)]
+            use super::super::*;
+
+            #(#scope_items)*
+        }
+
+        #(#registered_impls)*
+    )));
+
+    Ok(quote!(#item))
+}
+
+/// Checks that no option which must be unique to a type (e.g. a key) is given in the attribute, as
+/// it would be shared by every registration.
+pub(crate) fn check_no_unique_options(attr: &RegisterAttr) -> syn::Result<()> {
+    let registry_options = attr.registries().filter_map(|registry| registry.options().unique());
+    match attr.options().unique().into_iter().chain(registry_options).next() {
+        Some((name, option)) => Err(syn::Error::new_spanned(
+            option,
+            format!("`{name}` must be unique to a type, so must be given to individual items with `#[type_registry({name} = ...)]`")
+        )),
+        None => Ok(())
+    }
+}
+
+/// Checks that no option which refers to types is given in the attribute, as the implementations
+/// are generated within the module, where paths relative to its parent don't resolve.
+fn check_no_type_refs(attr: &RegisterAttr) -> syn::Result<()> {
+    let registry_options = attr.registries().filter_map(|registry| registry.options().type_refs());
+    match attr.options().type_refs().into_iter().chain(registry_options).next() {
+        Some((name, option)) => Err(syn::Error::new_spanned(
+            option,
+            format!("`{name}` isn't supported by register_all, give it to individual items with `#[type_registry({name} = [...])]`")
+        )),
+        None => Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{Expr, Token};
+use syn::parse::{Parse, ParseStream};
+use crate::instances_arg::InstancesArg;
+use crate::registration_option::RegistrationOption;
+use crate::registry_arg::RegistryArg;
+
+/// A single argument to the register attribute.
+pub(crate) enum RegisterArg {
+    Instances(InstancesArg),
+    Option(RegistrationOption),
+    Registry(Box<RegistryArg>)
+}
+
+impl Parse for RegisterArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if InstancesArg::peek(input) {
+            Ok(Self::Instances(Parse::parse(input)?))
+        } else if RegistrationOption::peek(input) {
+            Ok(Self::Option(Parse::parse(input)?))
+        } else {
+            // Type-info initialisation expressions used to be given as a separate argument after
+            // the registry, e.g. `#[register(MyRegistry, MyTypeInfo::custom())]`
+            let fork = input.fork();
+            let is_registry = fork.parse::<RegistryArg>().is_ok()
+                && (fork.is_empty() || fork.peek(Token![,]));
+            if !is_registry {
+                if let Ok(expr) = input.fork().parse::<Expr>() {
+                    return Err(old_init_form_error(expr))
+                }
+            }
+
+            Ok(Self::Registry(Parse::parse(input)?))
+        }
+    }
+}
+
+/// The error for an argument which looks like a type-info initialisation expression given in the
+/// old form, as a separate argument after the registry.
+pub(crate) fn old_init_form_error(arg: impl ToTokens) -> syn::Error {
+    syn::Error::new_spanned(
+        arg,
+        "expected a registry, found an expression; type-info initialisation expressions are now given in parentheses after their registry, e.g. `#[register(MyRegistry(expr))]` rather than `#[register(MyRegistry, expr)]`"
+    )
+}
+
+impl ToTokens for RegisterArg {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            RegisterArg::Instances(arg) => arg.to_tokens(tokens),
+            RegisterArg::Option(option) => option.to_tokens(tokens),
+            RegisterArg::Registry(arg) => arg.to_tokens(tokens)
+        }
+    }
+}
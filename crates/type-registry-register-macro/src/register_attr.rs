@@ -1,57 +1,91 @@
-use proc_macro2::{Ident, TokenStream};
-use quote::{quote, ToTokens};
-use syn::{Expr, Path, Token, Type};
+use std::collections::HashSet;
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::Token;
 use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use crate::instances_arg::InstancesArg;
+use crate::register_arg::{old_init_form_error, RegisterArg};
+use crate::registration_options::RegistrationOptions;
+use crate::registry_arg::RegistryArg;
 
 pub(crate) struct RegisterAttr {
-    registry: Type,
-    comma: Option<Token![,]>,
-    init_type_info_expr: Option<Expr>
+    args: Punctuated<RegisterArg, Token![,]>,
+    options: RegistrationOptions
 }
 
 impl RegisterAttr {
-    pub fn into_parts(self, ident: &Ident, crate_: &Path) -> (Type, Expr) {
-        let Self {
-            registry,
-            init_type_info_expr,
-            ..
-        } = self;
-        
-        let init_type_info_expr = init_type_info_expr.unwrap_or_else(
-            || Self::default_init_type_info_expr(&registry, ident, crate_)
-        );
+    /// The registries to register with.
+    pub fn registries(&self) -> impl Iterator<Item=&RegistryArg> {
+        self.args.iter().filter_map(|arg| match arg {
+            RegisterArg::Registry(registry) => Some(registry.as_ref()),
+            _ => None
+        })
+    }
 
-        (registry, init_type_info_expr)
+    /// The options which apply to all registries (unless overridden by a registry's own options).
+    pub fn options(&self) -> &RegistrationOptions {
+        &self.options
     }
-    
-    fn default_init_type_info_expr(registry: &Type, ident: &Ident, crate_: &Path) -> Expr {
-        let default_expr: proc_macro::TokenStream = quote! { 
-            <#registry as #crate_::Registry>::TypeInfo::new::<#ident>()
-        }.into();
-        
-        syn::parse(default_expr).expect("default expression is well-formed")
+
+    /// The explicitly-listed instantiations of the registered (generic) type, if any.
+    pub fn instances(&self) -> Option<&InstancesArg> {
+        self.args.iter().find_map(|arg| match arg {
+            RegisterArg::Instances(instances) => Some(instances),
+            _ => None
+        })
     }
 }
 
 impl Parse for RegisterAttr {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let registry = Parse::parse(input)?;
-        let comma: Option<Token![,]> = Parse::parse(input)?;
-        let init_type_info_expr: Option<Expr> = if comma.is_some() && !input.is_empty() {
-            Some(Parse::parse(input)?)
-        } else {
-            None
-        };
-        
-        if !input.is_empty() {
-            return Err(input.error("extra tokens at end of input"))
+        let args: Punctuated<RegisterArg, Token![,]> = Punctuated::parse_terminated(input)?;
+
+        // Type-info initialisation expressions used to be given as a separate argument after the
+        // registry, e.g. `#[register(MyRegistry, DEFAULT_SIZE)]`
+        let mut registries = args.iter().filter_map(|arg| match arg {
+            RegisterArg::Registry(registry) => Some(registry),
+            _ => None
+        });
+        if let Some(value) = registries.by_ref().skip(1).find(|registry| registry.looks_like_value()) {
+            return Err(old_init_form_error(value))
         }
-        
+
+        let mut seen_instances = false;
+        let mut seen_registries = HashSet::new();
+        for arg in &args {
+            match arg {
+                RegisterArg::Instances(_) => {
+                    if seen_instances {
+                        return Err(syn::Error::new_spanned(arg, "duplicate `instances` argument"))
+                    }
+                    seen_instances = true;
+                },
+                RegisterArg::Option(_) => {},
+                RegisterArg::Registry(registry) => {
+                    let registry = registry.registry();
+                    if !seen_registries.insert(registry.to_token_stream().to_string()) {
+                        return Err(syn::Error::new_spanned(registry, "duplicate registry"))
+                    }
+                }
+            }
+        }
+
+        if seen_registries.is_empty() {
+            return Err(input.error("expected at least one registry"))
+        }
+
+        let options = RegistrationOptions::collect(
+            args.iter().filter_map(|arg| match arg {
+                RegisterArg::Option(option) => Some(option),
+                _ => None
+            })
+        )?;
+
         Ok(
             Self {
-                registry,
-                comma,
-                init_type_info_expr
+                args,
+                options
             }
         )
     }
@@ -59,12 +93,6 @@ impl Parse for RegisterAttr {
 
 impl ToTokens for RegisterAttr {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        self.registry.to_tokens(tokens);
-        if let Some(comma) = self.comma {
-            comma.to_tokens(tokens);
-        }
-        if let Some(init_type_info_expr) = &self.init_type_info_expr {
-            init_type_info_expr.to_tokens(tokens);
-        }
+        self.args.to_tokens(tokens);
     }
-}
\ No newline at end of file
+}
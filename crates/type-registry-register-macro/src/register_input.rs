@@ -1,30 +1,53 @@
-use std::mem::discriminant;
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::TokenStream;
 use quote::ToTokens;
-use syn::{Attribute, AttrStyle, Generics, Item, Meta, Path};
+use syn::{Attribute, Generics, Item, parse_quote, Path, StaticMutability, Type};
 use syn::parse::{Parse, ParseStream};
 use crate::crate_attribute::CrateAttribute;
+use crate::fn_marker::fn_marker;
+use crate::fn_pointer::check_signature;
+use crate::value_marker::value_marker;
 
 pub(crate) enum RegisterInput {
     Struct(syn::ItemStruct),
     Enum(syn::ItemEnum),
-    Union(syn::ItemUnion)
+    Union(syn::ItemUnion),
+    /// A trait implementation, registering the implementation's self type.
+    Impl(syn::ItemImpl),
+    /// A function, registered via a generated marker type of the same name.
+    Fn(syn::ItemFn),
+    /// A static, registered via a generated marker type of the same name.
+    Static(syn::ItemStatic),
+    /// A const, registered via a generated marker type of the same name.
+    Const(syn::ItemConst)
 }
 
 impl RegisterInput {
-    pub fn ident(&self) -> &Ident {
+    /// The type to register. For trait implementations this is the implementation's self type.
+    pub fn registered_type(&self) -> Type {
         match self {
-            RegisterInput::Struct(item) => &item.ident,
-            RegisterInput::Enum(item) => &item.ident,
-            RegisterInput::Union(item) => &item.ident
+            RegisterInput::Struct(item) => Self::ident_type(&item.ident),
+            RegisterInput::Enum(item) => Self::ident_type(&item.ident),
+            RegisterInput::Union(item) => Self::ident_type(&item.ident),
+            RegisterInput::Impl(item) => item.self_ty.as_ref().clone(),
+            RegisterInput::Fn(item) => Self::ident_type(&item.sig.ident),
+            RegisterInput::Static(item) => Self::ident_type(&item.ident),
+            RegisterInput::Const(item) => Self::ident_type(&item.ident)
         }
     }
+
+    fn ident_type(ident: &syn::Ident) -> Type {
+        parse_quote!(#ident)
+    }
     
-    pub fn generics(&self) -> &Generics {
+    pub fn generics(&self) -> Generics {
         match self {
-            RegisterInput::Struct(item) => &item.generics,
-            RegisterInput::Enum(item) => &item.generics,
-            RegisterInput::Union(item) => &item.generics
+            RegisterInput::Struct(item) => item.generics.clone(),
+            RegisterInput::Enum(item) => item.generics.clone(),
+            RegisterInput::Union(item) => item.generics.clone(),
+            RegisterInput::Impl(item) => item.generics.clone(),
+            RegisterInput::Fn(item) => item.sig.generics.clone(),
+            RegisterInput::Static(_) => Generics::default(),
+            RegisterInput::Const(item) => item.generics.clone()
         }
     }
     
@@ -32,41 +55,26 @@ impl RegisterInput {
         match self {
             RegisterInput::Struct(item) => &mut item.attrs,
             RegisterInput::Enum(item) => &mut item.attrs,
-            RegisterInput::Union(item) => &mut item.attrs
+            RegisterInput::Union(item) => &mut item.attrs,
+            RegisterInput::Impl(item) => &mut item.attrs,
+            RegisterInput::Fn(item) => &mut item.attrs,
+            RegisterInput::Static(item) => &mut item.attrs,
+            RegisterInput::Const(item) => &mut item.attrs
         }
     }
-    
-    fn type_registry_attributes(&mut self) -> Vec<TokenStream> {
-        let mut our_attrs = Vec::new();
-
-        let extract = |attr: &Attribute| {
-            if discriminant(&attr.style) != discriminant(&AttrStyle::Outer) { return true }
-            let meta = match &attr.meta {
-                Meta::List(meta) => meta,
-                _ => return true
-            };
-            if !meta.path.is_ident("type_registry") { return true }
-            our_attrs.push(meta.tokens.clone());
-            false
-        };
 
-        self.attrs().retain(extract);
-        
-        our_attrs
-    }
-    
-    pub fn crate_(&mut self) -> syn::Result<Path> {
-        let our_attrs = self.type_registry_attributes();
-        
-        for attr in our_attrs {
-            return Ok(syn::parse2::<CrateAttribute>(attr)?.into_path())
+    /// Any items which need to be generated alongside the input to make it registrable.
+    pub fn supporting_items(&self, crate_: &Path) -> TokenStream {
+        match self {
+            RegisterInput::Fn(item) => fn_marker(crate_, item),
+            RegisterInput::Static(item) => value_marker(crate_, &item.vis, &item.ident, &item.ty, true),
+            RegisterInput::Const(item) => value_marker(crate_, &item.vis, &item.ident, &item.ty, false),
+            _ => TokenStream::new()
         }
-        
-        Ok(Self::default_crate())
     }
     
-    fn default_crate() -> Path {
-        syn::parse_str("::type_registry").expect("path is well-formed")
+    pub fn crate_(&mut self) -> syn::Result<Path> {
+        CrateAttribute::extract(self.attrs())
     }
 }
 
@@ -76,7 +84,20 @@ impl Parse for RegisterInput {
             Item::Enum(item) => Self::Enum(item),
             Item::Struct(item) => Self::Struct(item),
             Item::Union(item) => Self::Union(item),
-            other => return Err(syn::Error::new_spanned(other, "only structs, enums and unions can be registered"))
+            Item::Impl(item) if item.trait_.is_some() => Self::Impl(item),
+            Item::Fn(item) => {
+                check_signature(&item.sig, "functions")?;
+                Self::Fn(item)
+            },
+            Item::Static(item) => {
+                if let StaticMutability::Mut(mutability) = &item.mutability {
+                    return Err(syn::Error::new_spanned(mutability, "mutable statics can't be registered"))
+                }
+                Self::Static(item)
+            },
+            Item::Const(item) => Self::Const(item),
+            Item::Impl(item) => return Err(syn::Error::new_spanned(item.self_ty, "only trait implementations can be registered, not inherent implementations")),
+            other => return Err(syn::Error::new_spanned(other, "only structs, enums, unions, trait implementations, functions, statics and consts can be registered"))
         };
 
         Ok(parsed)
@@ -89,6 +110,10 @@ impl ToTokens for RegisterInput {
             RegisterInput::Struct(item) => item.to_tokens(tokens),
             RegisterInput::Enum(item) => item.to_tokens(tokens),
             RegisterInput::Union(item) => item.to_tokens(tokens),
+            RegisterInput::Impl(item) => item.to_tokens(tokens),
+            RegisterInput::Fn(item) => item.to_tokens(tokens),
+            RegisterInput::Static(item) => item.to_tokens(tokens),
+            RegisterInput::Const(item) => item.to_tokens(tokens),
         }
     }
 }
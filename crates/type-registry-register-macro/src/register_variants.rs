@@ -0,0 +1,122 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Expr, ItemEnum, parse_quote, Type};
+use crate::crate_attribute::CrateAttribute;
+use crate::register_attr::RegisterAttr;
+use crate::register_all::check_no_unique_options;
+use crate::registered_impl::registered_impl;
+use crate::registration_options::RegistrationOptions;
+use crate::type_registry_attribute::TypeRegistryAttribute;
+
+/// Registers each variant of an enum individually, via a hidden marker type per variant. The
+/// markers are named after the enum and variant, and share the enum's visibility.
+pub(crate) fn register_variants(attr: RegisterAttr, mut item: ItemEnum) -> syn::Result<TokenStream> {
+    if let Some(instances) = attr.instances() {
+        return Err(syn::Error::new_spanned(instances, "`instances(...)` is not supported by register_variants"))
+    }
+
+    let generics = &item.generics;
+    if !generics.params.is_empty() || generics.where_clause.is_some() {
+        return Err(syn::Error::new_spanned(generics, "enums with generic variants can't be registered"))
+    }
+
+    check_no_unique_options(&attr)?;
+
+    let crate_ = CrateAttribute::extract(&mut item.attrs)?;
+
+    let vis = &item.vis;
+    let ident = &item.ident;
+
+    let mut marker_items = Vec::new();
+    let mut registered_impls = Vec::new();
+    let mut variant_idents = Vec::new();
+    let mut markers = Vec::new();
+
+    // Implicit discriminants count up from the last explicit discriminant (or zero)
+    let mut discriminant_base: Option<Expr> = None;
+    let mut discriminant_offset: i128 = 0;
+
+    for variant in item.variants.iter_mut() {
+        let mut variant_init_type_info_expr = None;
+        let mut variant_options = Vec::new();
+        for attr in TypeRegistryAttribute::extract(&mut variant.attrs)? {
+            match attr {
+                TypeRegistryAttribute::Init { expr, .. } if variant_init_type_info_expr.is_none() => variant_init_type_info_expr = Some(expr),
+                TypeRegistryAttribute::Option(option) => variant_options.push(option),
+                other => return Err(other.unexpected())
+            }
+        }
+        let variant_options = RegistrationOptions::collect(&variant_options)?;
+
+        if let Some((_, discriminant)) = &variant.discriminant {
+            discriminant_base = Some(discriminant.clone());
+            discriminant_offset = 0;
+        }
+        let discriminant = match &discriminant_base {
+            Some(base) => quote!((#base) as i128 + #discriminant_offset),
+            None => quote!(#discriminant_offset)
+        };
+        discriminant_offset += 1;
+
+        let variant_ident = &variant.ident;
+        let field_count = variant.fields.len();
+
+        let marker_ident = format_ident!("__{}_{}", ident, variant_ident);
+        marker_items.push(quote!(
+            #[doc(hidden)]
+            #[allow(non_camel_case_types)]
+            #vis struct #marker_ident {}
+        ));
+
+        let marker: Type = parse_quote!(#marker_ident);
+
+        registered_impls.push(quote!(
+            impl #crate_::RegisteredVariant for #marker {
+                type Enum = #ident;
+
+                const NAME: &'static str = ::core::stringify!(#variant_ident);
+
+                const DISCRIMINANT: i128 = #discriminant;
+
+                const FIELD_COUNT: usize = #field_count;
+            }
+        ));
+
+        for registry in attr.registries() {
+            let init_type_info_expr = variant_init_type_info_expr.clone().unwrap_or_else(
+                || registry.init_type_info_expr(&marker, &crate_)
+            );
+            let options = attr.options().overridden_by(registry.options()).overridden_by(&variant_options);
+            let item_name = format!("{ident}::{variant_ident}");
+            registered_impls.push(registered_impl(&crate_, registry.registry(), &marker, Some(&item_name), &init_type_info_expr, &options));
+        }
+
+        variant_idents.push(variant_ident.clone());
+        markers.push(marker);
+    }
+
+    let registered_variants_impls = attr.registries().map(|registry| {
+        let registry = registry.registry();
+        quote!(
+            impl #crate_::RegisteredVariants<#registry> for #ident {
+                fn registration_id(&self) -> #crate_::RegistrationId<#registry> {
+                    match *self {
+                        #(Self::#variant_idents { .. } => #crate_::RegistrationId::of::<#markers>()),*
+                    }
+                }
+            }
+        )
+    });
+
+    Ok(
+        quote!(
+            #item
+
+            #(#marker_items)*
+
+            #(#registered_impls)*
+
+            #(#registered_variants_impls)*
+        )
+    )
+}
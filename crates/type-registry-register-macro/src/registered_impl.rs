@@ -0,0 +1,48 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{Expr, Path, Type};
+use crate::registration_options::RegistrationOptions;
+
+/// Generates the implementation of `Registered` for a single (concrete) type. `item_name` is the
+/// name of the registered item within its module (if it's defined there), from which its default
+/// stable name is derived.
+pub(crate) fn registered_impl(
+    crate_: &Path,
+    registry: &Type,
+    registered: &Type,
+    item_name: Option<&str>,
+    init_type_info_expr: &Expr,
+    options: &RegistrationOptions
+) -> TokenStream {
+    let modifiers = options.modifiers(crate_, item_name);
+
+    quote!(
+        unsafe impl #crate_::Registered<#registry> for #registered {
+            fn register() -> #crate_::Registration<#registry, Self> {
+                 #crate_::registration!(#registry, #registered #modifiers)
+            }
+
+            fn type_info() -> &'static <#registry as #crate_::Registry>::TypeInfo {
+                 static TYPE_INFO: <#registry as #crate_::Registry>::TypeInfo = #init_type_info_expr;
+                 &TYPE_INFO
+            }
+        }
+    )
+}
+
+/// The name of a type for use in its default stable name. This is the type as written, with
+/// whitespace only kept between adjacent identifiers (e.g. `Wrapper<&'static dyn Trait>`), so it
+/// only depends on the source and not on the compiler.
+pub(crate) fn stable_type_name(ty: &Type) -> String {
+    let tokens = ty.to_token_stream().to_string();
+    let is_ident_char = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+
+    let mut name = String::with_capacity(tokens.len());
+    let mut chars = tokens.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != ' ' || (is_ident_char(name.chars().last()) && is_ident_char(chars.peek().copied())) {
+            name.push(c);
+        }
+    }
+    name
+}
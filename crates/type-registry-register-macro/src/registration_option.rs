@@ -0,0 +1,276 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{bracketed, Ident, LitInt, LitStr, Token, Type};
+use syn::punctuated::Punctuated;
+use syn::token::{Bracket, Paren};
+use crate::deprecated_option::DeprecatedOption;
+use crate::version_syntax::{check_requirement, check_version};
+use syn::parse::{Parse, ParseStream};
+
+/// A named option which customises a registration, of the form `name = value`.
+pub(crate) enum RegistrationOption {
+    /// `key = "..."`, the key to register under.
+    Key {
+        keyword: Ident,
+        equals: Token![=],
+        key: LitStr
+    },
+    /// `id = ...`, the explicit numeric ID to register with.
+    Id {
+        keyword: Ident,
+        equals: Token![=],
+        id: LitInt
+    },
+    /// `stable_name = "..."`, the stable name to register with (instead of the default).
+    StableName {
+        keyword: Ident,
+        equals: Token![=],
+        stable_name: LitStr
+    },
+    /// `priority = ...`, the priority of the registration over others with the same key.
+    Priority {
+        keyword: Ident,
+        equals: Token![=],
+        minus: Option<Token![-]>,
+        priority: LitInt
+    },
+    /// `weak`, marking the registration as a fallback for others with the same key.
+    Weak {
+        keyword: Ident
+    },
+    /// `after = [...]`, the types the registration must be ordered after.
+    After {
+        keyword: Ident,
+        equals: Token![=],
+        bracket: Bracket,
+        types: Punctuated<Type, Token![,]>
+    },
+    /// `before = [...]`, the types the registration must be ordered before.
+    Before {
+        keyword: Ident,
+        equals: Token![=],
+        bracket: Bracket,
+        types: Punctuated<Type, Token![,]>
+    },
+    /// `tags = [...]`, the tags to register with.
+    Tags {
+        keyword: Ident,
+        equals: Token![=],
+        bracket: Bracket,
+        tags: Punctuated<LitStr, Token![,]>
+    },
+    /// `aliases = [...]`, alternative keys to register under.
+    Aliases {
+        keyword: Ident,
+        equals: Token![=],
+        bracket: Bracket,
+        aliases: Punctuated<LitStr, Token![,]>
+    },
+    /// `deprecated(...)`, marking the registration as deprecated.
+    Deprecated(DeprecatedOption),
+    /// `version = "..."`, the version to register with.
+    Version {
+        keyword: Ident,
+        equals: Token![=],
+        version: LitStr
+    },
+    /// `requires = "..."`, the requirement on the host version to register with.
+    Requires {
+        keyword: Ident,
+        equals: Token![=],
+        requires: LitStr
+    }
+}
+
+impl RegistrationOption {
+    /// The options which are given as a bare name, rather than `name = value`.
+    const FLAGS: [&'static str; 2] = ["weak", DeprecatedOption::KEYWORD];
+
+    /// Whether the input stream starts with a named option.
+    pub fn peek(input: ParseStream) -> bool {
+        if input.peek(syn::Ident) && input.peek2(Token![=]) && !input.peek2(Token![==]) {
+            return true
+        }
+
+        let fork = input.fork();
+        match fork.parse::<Ident>() {
+            Ok(keyword) if keyword == DeprecatedOption::KEYWORD && fork.peek(Paren) => true,
+            Ok(keyword) => Self::FLAGS.iter().any(|flag| keyword == flag) && (fork.is_empty() || fork.peek(Token![,])),
+            Err(_) => false
+        }
+    }
+
+    /// The name of the option.
+    pub fn keyword(&self) -> &Ident {
+        match self {
+            RegistrationOption::Key { keyword, .. } => keyword,
+            RegistrationOption::Id { keyword, .. } => keyword,
+            RegistrationOption::StableName { keyword, .. } => keyword,
+            RegistrationOption::Priority { keyword, .. } => keyword,
+            RegistrationOption::Weak { keyword } => keyword,
+            RegistrationOption::After { keyword, .. } => keyword,
+            RegistrationOption::Before { keyword, .. } => keyword,
+            RegistrationOption::Tags { keyword, .. } => keyword,
+            RegistrationOption::Aliases { keyword, .. } => keyword,
+            RegistrationOption::Deprecated(deprecated) => deprecated.keyword(),
+            RegistrationOption::Version { keyword, .. } => keyword,
+            RegistrationOption::Requires { keyword, .. } => keyword
+        }
+    }
+}
+
+impl Parse for RegistrationOption {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.fork().parse::<Ident>().is_ok_and(|keyword| keyword == DeprecatedOption::KEYWORD) {
+            return Ok(Self::Deprecated(Parse::parse(input)?))
+        }
+
+        let keyword: Ident = Parse::parse(input)?;
+
+        if keyword == "key" {
+            Ok(
+                Self::Key {
+                    keyword,
+                    equals: Parse::parse(input)?,
+                    key: Parse::parse(input)?
+                }
+            )
+        } else if keyword == "version" {
+            let equals = Parse::parse(input)?;
+            let version = Parse::parse(input)?;
+            check_version(&version)?;
+            Ok(
+                Self::Version {
+                    keyword,
+                    equals,
+                    version
+                }
+            )
+        } else if keyword == "requires" {
+            let equals = Parse::parse(input)?;
+            let requires = Parse::parse(input)?;
+            check_requirement(&requires)?;
+            Ok(
+                Self::Requires {
+                    keyword,
+                    equals,
+                    requires
+                }
+            )
+        } else if keyword == "id" {
+            let equals = Parse::parse(input)?;
+            let id: LitInt = Parse::parse(input)?;
+            id.base10_parse::<u32>()?;
+            Ok(
+                Self::Id {
+                    keyword,
+                    equals,
+                    id
+                }
+            )
+        } else if keyword == "stable_name" {
+            Ok(
+                Self::StableName {
+                    keyword,
+                    equals: Parse::parse(input)?,
+                    stable_name: Parse::parse(input)?
+                }
+            )
+        } else if keyword == "priority" {
+            let equals = Parse::parse(input)?;
+            let minus: Option<Token![-]> = Parse::parse(input)?;
+            let priority: LitInt = Parse::parse(input)?;
+            let sign = if minus.is_some() { "-" } else { "" };
+            if format!("{sign}{}", priority.base10_digits()).parse::<i32>().is_err() {
+                return Err(syn::Error::new_spanned(priority, "priority must be an `i32`"))
+            }
+            Ok(
+                Self::Priority {
+                    keyword,
+                    equals,
+                    minus,
+                    priority
+                }
+            )
+        } else if keyword == "weak" {
+            Ok(Self::Weak { keyword })
+        } else if keyword == "after" || keyword == "before" {
+            let equals = Parse::parse(input)?;
+            let content;
+            let bracket = bracketed!(content in input);
+            let types = Punctuated::parse_terminated(&content)?;
+            if keyword == "after" {
+                Ok(Self::After { keyword, equals, bracket, types })
+            } else {
+                Ok(Self::Before { keyword, equals, bracket, types })
+            }
+        } else if keyword == "tags" || keyword == "aliases" {
+            let equals = Parse::parse(input)?;
+            let content;
+            let bracket = bracketed!(content in input);
+            let strings = Punctuated::parse_terminated(&content)?;
+            if keyword == "tags" {
+                Ok(Self::Tags { keyword, equals, bracket, tags: strings })
+            } else {
+                Ok(Self::Aliases { keyword, equals, bracket, aliases: strings })
+            }
+        } else {
+            Err(syn::Error::new_spanned(
+                keyword,
+                "unknown option, expected `key`, `id`, `stable_name`, `priority`, `weak`, `after`, `before`, `tags`, `aliases`, `deprecated`, `version` or `requires`"
+            ))
+        }
+    }
+}
+
+impl ToTokens for RegistrationOption {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            RegistrationOption::Key { keyword, equals, key } => {
+                keyword.to_tokens(tokens);
+                equals.to_tokens(tokens);
+                key.to_tokens(tokens);
+            },
+            RegistrationOption::Id { keyword, equals, id } => {
+                keyword.to_tokens(tokens);
+                equals.to_tokens(tokens);
+                id.to_tokens(tokens);
+            },
+            RegistrationOption::StableName { keyword, equals, stable_name } => {
+                keyword.to_tokens(tokens);
+                equals.to_tokens(tokens);
+                stable_name.to_tokens(tokens);
+            },
+            RegistrationOption::Priority { keyword, equals, minus, priority } => {
+                keyword.to_tokens(tokens);
+                equals.to_tokens(tokens);
+                minus.to_tokens(tokens);
+                priority.to_tokens(tokens);
+            },
+            RegistrationOption::Weak { keyword } => {
+                keyword.to_tokens(tokens);
+            },
+            RegistrationOption::After { keyword, equals, bracket, types }
+            | RegistrationOption::Before { keyword, equals, bracket, types } => {
+                keyword.to_tokens(tokens);
+                equals.to_tokens(tokens);
+                bracket.surround(tokens, |tokens| types.to_tokens(tokens));
+            },
+            RegistrationOption::Tags { keyword, equals, bracket, tags: strings }
+            | RegistrationOption::Aliases { keyword, equals, bracket, aliases: strings } => {
+                keyword.to_tokens(tokens);
+                equals.to_tokens(tokens);
+                bracket.surround(tokens, |tokens| strings.to_tokens(tokens));
+            },
+            RegistrationOption::Deprecated(deprecated) => {
+                deprecated.to_tokens(tokens);
+            },
+            RegistrationOption::Version { keyword, equals, version: string }
+            | RegistrationOption::Requires { keyword, equals, requires: string } => {
+                keyword.to_tokens(tokens);
+                equals.to_tokens(tokens);
+                string.to_tokens(tokens);
+            }
+        }
+    }
+}
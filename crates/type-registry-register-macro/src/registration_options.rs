@@ -0,0 +1,154 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{Ident, LitInt, LitStr, Path, Token, Type};
+use syn::punctuated::Punctuated;
+use crate::deprecated_option::DeprecatedOption;
+use crate::registration_option::RegistrationOption;
+
+/// The collected [options](RegistrationOption) for a registration.
+#[derive(Clone, Default)]
+pub(crate) struct RegistrationOptions {
+    key: Option<LitStr>,
+    id: Option<LitInt>,
+    stable_name: Option<LitStr>,
+    priority: Option<TokenStream>,
+    weak: Option<Ident>,
+    after: Option<Punctuated<Type, Token![,]>>,
+    before: Option<Punctuated<Type, Token![,]>>,
+    tags: Option<Punctuated<LitStr, Token![,]>>,
+    aliases: Option<Punctuated<LitStr, Token![,]>>,
+    deprecated: Option<DeprecatedOption>,
+    version: Option<LitStr>,
+    requires: Option<LitStr>
+}
+
+impl RegistrationOptions {
+    /// Collects the given options, reporting any given more than once.
+    pub fn collect<'a>(options: impl IntoIterator<Item=&'a RegistrationOption>) -> syn::Result<Self> {
+        let mut collected = Self::default();
+
+        for option in options {
+            let duplicate = match option {
+                RegistrationOption::Key { key, .. } => collected.key.replace(key.clone()).is_some(),
+                RegistrationOption::Id { id, .. } => collected.id.replace(id.clone()).is_some(),
+                RegistrationOption::StableName { stable_name, .. } => collected.stable_name.replace(stable_name.clone()).is_some(),
+                RegistrationOption::Priority { minus, priority, .. } => collected.priority.replace(quote!(#minus #priority)).is_some(),
+                RegistrationOption::Weak { keyword } => collected.weak.replace(keyword.clone()).is_some(),
+                RegistrationOption::After { types, .. } => collected.after.replace(types.clone()).is_some(),
+                RegistrationOption::Before { types, .. } => collected.before.replace(types.clone()).is_some(),
+                RegistrationOption::Tags { tags, .. } => collected.tags.replace(tags.clone()).is_some(),
+                RegistrationOption::Aliases { aliases, .. } => collected.aliases.replace(aliases.clone()).is_some(),
+                RegistrationOption::Deprecated(deprecated) => collected.deprecated.replace(deprecated.clone()).is_some(),
+                RegistrationOption::Version { version, .. } => collected.version.replace(version.clone()).is_some(),
+                RegistrationOption::Requires { requires, .. } => collected.requires.replace(requires.clone()).is_some()
+            };
+
+            if duplicate {
+                return Err(syn::Error::new_spanned(option.keyword(), "duplicate option"))
+            }
+        }
+
+        Ok(collected)
+    }
+
+    /// The first option given which must be unique to a single registered type (along with its
+    /// name), if any.
+    pub fn unique(&self) -> Option<(&'static str, &dyn ToTokens)> {
+        let key = self.key.as_ref().map(|key| ("key", key as &dyn ToTokens));
+        key.or_else(|| self.id.as_ref().map(|id| ("id", id as &dyn ToTokens)))
+            .or_else(|| self.stable_name.as_ref().map(|stable_name| ("stable_name", stable_name as &dyn ToTokens)))
+            .or_else(|| self.aliases.as_ref().map(|aliases| ("aliases", aliases as &dyn ToTokens)))
+    }
+
+    /// The first option given which refers to types (along with its name), if any.
+    pub fn type_refs(&self) -> Option<(&'static str, &dyn ToTokens)> {
+        let after = self.after.as_ref().map(|after| ("after", after as &dyn ToTokens));
+        after.or_else(|| self.before.as_ref().map(|before| ("before", before as &dyn ToTokens)))
+    }
+
+    /// These options, with any options given in `overrides` taking precedence.
+    pub fn overridden_by(&self, overrides: &Self) -> Self {
+        Self {
+            key: overrides.key.clone().or_else(|| self.key.clone()),
+            id: overrides.id.clone().or_else(|| self.id.clone()),
+            stable_name: overrides.stable_name.clone().or_else(|| self.stable_name.clone()),
+            priority: overrides.priority.clone().or_else(|| self.priority.clone()),
+            weak: overrides.weak.clone().or_else(|| self.weak.clone()),
+            after: overrides.after.clone().or_else(|| self.after.clone()),
+            before: overrides.before.clone().or_else(|| self.before.clone()),
+            tags: overrides.tags.clone().or_else(|| self.tags.clone()),
+            aliases: overrides.aliases.clone().or_else(|| self.aliases.clone()),
+            deprecated: overrides.deprecated.clone().or_else(|| self.deprecated.clone()),
+            version: overrides.version.clone().or_else(|| self.version.clone()),
+            requires: overrides.requires.clone().or_else(|| self.requires.clone())
+        }
+    }
+
+    /// The modifiers of the raw entry to pass to the `registration!` macro. Unless given
+    /// explicitly, the stable name is the module path followed by `item_name` (the name of the
+    /// registered item within its module), if any.
+    pub fn modifiers(&self, crate_: &Path, item_name: Option<&str>) -> TokenStream {
+        let mut modifiers = Vec::new();
+
+        match (&self.stable_name, item_name) {
+            (Some(stable_name), _) => modifiers.push(quote!(with_stable_name(#stable_name))),
+            (None, Some(item_name)) => modifiers.push(quote!(with_stable_name(::core::concat!(::core::module_path!(), "::", #item_name)))),
+            (None, None) => {}
+        }
+
+        if let Some(key) = &self.key {
+            modifiers.push(quote!(with_key(#key)));
+        }
+
+        if let Some(id) = &self.id {
+            modifiers.push(quote!(with_explicit_id(#id)));
+        }
+
+        if let Some(priority) = &self.priority {
+            modifiers.push(quote!(with_priority(#priority)));
+        }
+
+        if self.weak.is_some() {
+            modifiers.push(quote!(as_weak()));
+        }
+
+        if let Some(after) = &self.after {
+            let after = after.iter();
+            modifiers.push(quote!(with_after(&[#(#crate_::raw::TypeRef::of::<#after>()),*])));
+        }
+
+        if let Some(before) = &self.before {
+            let before = before.iter();
+            modifiers.push(quote!(with_before(&[#(#crate_::raw::TypeRef::of::<#before>()),*])));
+        }
+
+        if let Some(tags) = &self.tags {
+            let tags = tags.iter();
+            modifiers.push(quote!(with_tags(&[#(#tags),*])));
+        }
+
+        if let Some(aliases) = &self.aliases {
+            let aliases = aliases.iter();
+            modifiers.push(quote!(with_aliases(&[#(#aliases),*])));
+        }
+
+        if let Some(deprecated) = &self.deprecated {
+            let deprecation = deprecated.deprecation(crate_);
+            modifiers.push(quote!(with_deprecation(#deprecation)));
+        }
+
+        if let Some(version) = &self.version {
+            modifiers.push(quote!(with_version(#version)));
+        }
+
+        if let Some(requires) = &self.requires {
+            modifiers.push(quote!(with_requires(#requires)));
+        }
+
+        if modifiers.is_empty() {
+            TokenStream::new()
+        } else {
+            quote!(; #(#modifiers),*)
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{parenthesized, Expr, Path, Token, Type, TypePath};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::token::Paren;
+use crate::registration_options::RegistrationOptions;
+use crate::registry_arg_item::RegistryArgItem;
+
+/// A registry to register with, optionally followed by a parenthesised expression which
+/// initialises the type-info and/or options specific to this registry, e.g. `MyRegistry`,
+/// `MyRegistry(MyTypeInfo::custom())` or `MyRegistry(MyTypeInfo::custom(), key = "custom")`.
+pub(crate) struct RegistryArg {
+    registry: Type,
+    paren: Option<Paren>,
+    items: Punctuated<RegistryArgItem, Token![,]>,
+    options: RegistrationOptions
+}
+
+impl RegistryArg {
+    pub fn registry(&self) -> &Type {
+        &self.registry
+    }
+
+    /// The options specific to this registry.
+    pub fn options(&self) -> &RegistrationOptions {
+        &self.options
+    }
+
+    /// Whether this looks like a value rather than a registry type, i.e. the registry's name
+    /// starts with a lowercase letter, like a function or variable (e.g. `MyTypeInfo::new()`), or
+    /// is all uppercase, like a constant (e.g. `DEFAULT_SIZE`).
+    pub fn looks_like_value(&self) -> bool {
+        let Type::Path(path) = &self.registry else { return false };
+        let Some(last) = path.path.segments.last() else { return false };
+        let name = last.ident.to_string();
+        let is_lowercase = name.starts_with(|c: char| c.is_lowercase());
+        let is_uppercase = name.len() > 1 && !name.chars().any(|c| c.is_lowercase());
+        is_lowercase || is_uppercase
+    }
+
+    /// The expression which initialises the type-info for the given registered type.
+    pub fn init_type_info_expr(&self, registered: &Type, crate_: &Path) -> Expr {
+        self.explicit_init_type_info_expr().cloned().unwrap_or_else(
+            || Self::default_init_type_info_expr(&self.registry, registered, crate_)
+        )
+    }
+
+    /// The explicitly-given type-info initialisation expression, if any.
+    pub fn explicit_init_type_info_expr(&self) -> Option<&Expr> {
+        self.items.iter().find_map(|item| match item {
+            RegistryArgItem::InitTypeInfo(expr) => Some(expr),
+            _ => None
+        })
+    }
+
+    /// The expression which initialises the type-info for the given registered type when no
+    /// explicit expression is given.
+    pub fn default_init_type_info_expr(registry: &Type, registered: &Type, crate_: &Path) -> Expr {
+        let default_expr: proc_macro::TokenStream = quote! {
+            <<#registry as #crate_::Registry>::TypeInfo as #crate_::TypeInfoFor<#registered>>::TYPE_INFO
+        }.into();
+
+        syn::parse(default_expr).expect("default expression is well-formed")
+    }
+}
+
+impl Parse for RegistryArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // Parsed as a path rather than any type, so that a following parenthesised init
+        // expression isn't mistaken for `Fn(...)`-style arguments
+        let registry = Type::Path(TypePath::parse(input)?);
+        let (paren, items) = if input.peek(Paren) {
+            let content;
+            let paren = parenthesized!(content in input);
+            (Some(paren), Punctuated::parse_terminated(&content)?)
+        } else {
+            (None, Punctuated::new())
+        };
+
+        let mut init_type_info_exprs = items.iter().filter(|item| matches!(item, RegistryArgItem::InitTypeInfo(_)));
+        if let Some(duplicate) = init_type_info_exprs.nth(1) {
+            return Err(syn::Error::new_spanned(duplicate, "duplicate type-info initialisation expression"))
+        }
+
+        let options = RegistrationOptions::collect(
+            items.iter().filter_map(|item| match item {
+                RegistryArgItem::Option(option) => Some(option),
+                _ => None
+            })
+        )?;
+
+        Ok(
+            Self {
+                registry,
+                paren,
+                items,
+                options
+            }
+        )
+    }
+}
+
+impl ToTokens for RegistryArg {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.registry.to_tokens(tokens);
+        if let Some(paren) = &self.paren {
+            paren.surround(tokens, |tokens| self.items.to_tokens(tokens));
+        }
+    }
+}
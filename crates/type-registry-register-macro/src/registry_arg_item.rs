@@ -0,0 +1,31 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::Expr;
+use syn::parse::{Parse, ParseStream};
+use crate::registration_option::RegistrationOption;
+
+/// An item within the parentheses following a [registry](crate::registry_arg::RegistryArg):
+/// either an option specific to that registry, or the type-info initialisation expression.
+pub(crate) enum RegistryArgItem {
+    Option(RegistrationOption),
+    InitTypeInfo(Expr)
+}
+
+impl Parse for RegistryArgItem {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if RegistrationOption::peek(input) {
+            Ok(Self::Option(Parse::parse(input)?))
+        } else {
+            Ok(Self::InitTypeInfo(Parse::parse(input)?))
+        }
+    }
+}
+
+impl ToTokens for RegistryArgItem {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            RegistryArgItem::Option(option) => option.to_tokens(tokens),
+            RegistryArgItem::InitTypeInfo(expr) => expr.to_tokens(tokens)
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use syn::{Expr, Ident, LitStr, Token, Type};
+use syn::parse::{Parse, ParseStream};
+
+/// The `#[registry(type_info = ..., name = "...", key = ..., on_deprecated_use = ...)]` attribute
+/// of the Registry derive. The `type_info` argument is required, the others are optional.
+pub(crate) struct RegistryAttribute {
+    type_info: Type,
+    name: Option<LitStr>,
+    key: Option<Type>,
+    on_deprecated_use: Option<Expr>
+}
+
+impl RegistryAttribute {
+    pub fn type_info(&self) -> &Type {
+        &self.type_info
+    }
+
+    /// The name of the registry, defaulting to the registry's identifier.
+    pub fn name(&self, registry: &Ident) -> LitStr {
+        self.name.clone().unwrap_or_else(|| LitStr::new(&registry.to_string(), registry.span()))
+    }
+
+    /// The type of the keys entries are looked up by, if the registry is keyed.
+    pub fn key(&self) -> Option<&Type> {
+        self.key.as_ref()
+    }
+
+    /// The function to call when a deprecated entry is used, if any.
+    pub fn on_deprecated_use(&self) -> Option<&Expr> {
+        self.on_deprecated_use.as_ref()
+    }
+}
+
+impl Parse for RegistryAttribute {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut type_info: Option<Type> = None;
+        let mut name: Option<LitStr> = None;
+        let mut key_type: Option<Type> = None;
+        let mut on_deprecated_use: Option<Expr> = None;
+
+        while !input.is_empty() {
+            let key: Ident = Parse::parse(input)?;
+            let _: Token![=] = Parse::parse(input)?;
+
+            let duplicate = if key == "type_info" {
+                type_info.replace(Parse::parse(input)?).is_some()
+            } else if key == "name" {
+                name.replace(Parse::parse(input)?).is_some()
+            } else if key == "key" {
+                key_type.replace(Parse::parse(input)?).is_some()
+            } else if key == "on_deprecated_use" {
+                on_deprecated_use.replace(Parse::parse(input)?).is_some()
+            } else {
+                return Err(syn::Error::new_spanned(key, "unknown argument, expected `type_info`, `name`, `key` or `on_deprecated_use`"))
+            };
+
+            if duplicate {
+                return Err(syn::Error::new_spanned(key, "duplicate argument"))
+            }
+
+            if !input.is_empty() {
+                let _: Token![,] = Parse::parse(input)?;
+            }
+        }
+
+        let Some(type_info) = type_info else {
+            return Err(input.error("missing `type_info` argument"))
+        };
+
+        Ok(
+            Self {
+                type_info,
+                name,
+                key: key_type,
+                on_deprecated_use
+            }
+        )
+    }
+}
@@ -0,0 +1,54 @@
+use syn::{DeriveInput, Path};
+use syn::parse::{Parse, ParseStream};
+use crate::crate_attribute::CrateAttribute;
+use crate::registry_attribute::RegistryAttribute;
+
+/// The input to the Registry derive.
+pub(crate) struct RegistryInput {
+    input: DeriveInput,
+    attr: RegistryAttribute,
+    crate_: Path
+}
+
+impl RegistryInput {
+    pub fn input(&self) -> &DeriveInput {
+        &self.input
+    }
+
+    pub fn attr(&self) -> &RegistryAttribute {
+        &self.attr
+    }
+
+    pub fn crate_(&self) -> &Path {
+        &self.crate_
+    }
+}
+
+impl Parse for RegistryInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let input: DeriveInput = Parse::parse(input)?;
+
+        let mut attr = None;
+        for registry_attr in &input.attrs {
+            if !registry_attr.path().is_ident("registry") { continue }
+            if attr.is_some() {
+                return Err(syn::Error::new_spanned(registry_attr, "duplicate `registry` attribute"))
+            }
+            attr = Some(registry_attr.parse_args::<RegistryAttribute>()?);
+        }
+
+        let Some(attr) = attr else {
+            return Err(syn::Error::new_spanned(&input.ident, "missing `#[registry(type_info = ...)]` attribute"))
+        };
+
+        let crate_ = CrateAttribute::extract(&mut input.attrs.clone())?;
+
+        Ok(
+            Self {
+                input,
+                attr,
+                crate_
+            }
+        )
+    }
+}
@@ -0,0 +1,80 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ItemTrait, parse_quote, TraitItem};
+use crate::crate_attribute::CrateAttribute;
+use crate::fn_pointer::{check_signature, fn_pointer_type};
+use crate::registry_trait_attr::RegistryTraitAttr;
+use crate::type_info_impls::type_info_impls;
+
+/// Generates a registry, and its type-info, for the implementors of a trait. The trait is given a
+/// `Registered` supertrait bound for the generated registry.
+pub(crate) fn registry_trait(attr: RegistryTraitAttr, mut item: ItemTrait) -> syn::Result<TokenStream> {
+    let generics = &item.generics;
+    if !generics.params.is_empty() || generics.where_clause.is_some() {
+        return Err(syn::Error::new_spanned(generics, "generic traits aren't supported"))
+    }
+
+    let crate_ = CrateAttribute::extract(&mut item.attrs)?;
+
+    let vis = &item.vis;
+    let trait_ = &item.ident;
+    let name = attr.name(trait_);
+    let registry = attr.registry(trait_);
+    let type_info = attr.type_info(trait_);
+
+    let mut field_idents = Vec::new();
+    let mut field_types = Vec::new();
+    for trait_item in &item.items {
+        let TraitItem::Fn(trait_fn) = trait_item else { continue };
+        check_signature(&trait_fn.sig, "registry trait functions")?;
+        field_idents.push(&trait_fn.sig.ident);
+        field_types.push(fn_pointer_type(&trait_fn.sig));
+    }
+
+    let registry_doc = format!("Registry of the implementors of [`{trait_}`].");
+    let type_info_doc = format!("The type-info of the implementors of [`{trait_}`].");
+    let type_infos_doc = format!("Iterates over the type-info of all types registered with [`{registry}`].");
+
+    let type_info_impls = type_info_impls(
+        &crate_,
+        vis,
+        &type_info,
+        &parse_quote!(),
+        &parse_quote!(#trait_),
+        field_idents.iter().copied()
+    );
+
+    item.supertraits.push(parse_quote!(#crate_::Registered<#registry>));
+
+    Ok(
+        quote!(
+            #item
+
+            #[doc = #registry_doc]
+            #vis struct #registry;
+
+            impl #crate_::Registry for #registry {
+                type TypeInfo = #type_info;
+
+                fn name() -> &'static str {
+                    #name
+                }
+            }
+
+            impl #registry {
+                #[doc = #type_infos_doc]
+                #vis fn type_infos() -> impl ::core::iter::Iterator<Item = &'static #type_info> {
+                    <Self as #crate_::RegistryExt>::iter().map(|(_id, entry)| entry.type_info())
+                }
+            }
+
+            #[doc = #type_info_doc]
+            #[derive(Copy, Clone)]
+            #vis struct #type_info {
+                #(#vis #field_idents: #field_types),*
+            }
+
+            #type_info_impls
+        )
+    )
+}
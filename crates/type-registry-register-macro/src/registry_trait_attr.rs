@@ -0,0 +1,73 @@
+use syn::{Expr, ExprLit, Ident, Lit, LitStr, MetaNameValue, Token};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+
+/// The arguments to the registry_trait attribute, e.g.
+/// `#[registry_trait(name = "My Registry", registry = MyRegistry, type_info = MyTypeInfo)]`.
+/// All arguments are optional.
+pub(crate) struct RegistryTraitAttr {
+    name: Option<LitStr>,
+    registry: Option<Ident>,
+    type_info: Option<Ident>
+}
+
+impl RegistryTraitAttr {
+    /// The name of the generated registry, defaulting to the trait's name.
+    pub fn name(&self, trait_: &Ident) -> LitStr {
+        self.name.clone().unwrap_or_else(|| LitStr::new(&trait_.to_string(), trait_.span()))
+    }
+
+    /// The identifier of the generated registry, defaulting to `<Trait>Registry`.
+    pub fn registry(&self, trait_: &Ident) -> Ident {
+        self.registry.clone().unwrap_or_else(|| Ident::new(&format!("{trait_}Registry"), trait_.span()))
+    }
+
+    /// The identifier of the generated type-info, defaulting to `<Trait>TypeInfo`.
+    pub fn type_info(&self, trait_: &Ident) -> Ident {
+        self.type_info.clone().unwrap_or_else(|| Ident::new(&format!("{trait_}TypeInfo"), trait_.span()))
+    }
+
+    fn parse_ident(value: &Expr) -> syn::Result<Ident> {
+        match value {
+            Expr::Path(path) => path.path.require_ident().cloned(),
+            other => Err(syn::Error::new_spanned(other, "expected an identifier"))
+        }
+    }
+
+    fn parse_str(value: &Expr) -> syn::Result<LitStr> {
+        match value {
+            Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) => Ok(lit.clone()),
+            other => Err(syn::Error::new_spanned(other, "expected a string literal"))
+        }
+    }
+}
+
+impl Parse for RegistryTraitAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let args: Punctuated<MetaNameValue, Token![,]> = Punctuated::parse_terminated(input)?;
+
+        let mut attr = Self {
+            name: None,
+            registry: None,
+            type_info: None
+        };
+
+        for arg in args {
+            let duplicate = if arg.path.is_ident("name") {
+                attr.name.replace(Self::parse_str(&arg.value)?).is_some()
+            } else if arg.path.is_ident("registry") {
+                attr.registry.replace(Self::parse_ident(&arg.value)?).is_some()
+            } else if arg.path.is_ident("type_info") {
+                attr.type_info.replace(Self::parse_ident(&arg.value)?).is_some()
+            } else {
+                return Err(syn::Error::new_spanned(arg.path, "unknown argument, expected `name`, `registry` or `type_info`"))
+            };
+
+            if duplicate {
+                return Err(syn::Error::new_spanned(arg.path, "duplicate argument"))
+            }
+        }
+
+        Ok(attr)
+    }
+}
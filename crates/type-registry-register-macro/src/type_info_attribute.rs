@@ -0,0 +1,42 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{Path, Token};
+use syn::parse::{Parse, ParseStream};
+
+/// The `#[type_info(trait = ...)]` attribute of the TypeInfo derive, naming the trait whose
+/// associated functions fill the type-info's fields.
+pub struct TypeInfoAttribute {
+    keyword: Token![trait],
+    equals: Token![=],
+    path: Path
+}
+
+impl TypeInfoAttribute {
+    pub fn into_path(self) -> Path {
+        self.path
+    }
+}
+
+impl Parse for TypeInfoAttribute {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let keyword = Parse::parse(input)?;
+        let equals = Parse::parse(input)?;
+        let path = Parse::parse(input)?;
+
+        Ok(
+            Self {
+                keyword,
+                equals,
+                path
+            }
+        )
+    }
+}
+
+impl ToTokens for TypeInfoAttribute {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.keyword.to_tokens(tokens);
+        self.equals.to_tokens(tokens);
+        self.path.to_tokens(tokens);
+    }
+}
@@ -0,0 +1,42 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{Generics, Ident, parse_quote, Path, Visibility};
+
+/// Generates the const `new::<T>()` fn of a type-info struct, initialising each (function
+/// pointer) field from the associated function of the same name in the given trait, along with
+/// the implementation of `TypeInfoFor<T>` which defers to it.
+pub(crate) fn type_info_impls<'a>(
+    crate_: &Path,
+    vis: &Visibility,
+    ident: &Ident,
+    generics: &Generics,
+    trait_: &Path,
+    field_idents: impl Iterator<Item=&'a Ident>
+) -> TokenStream {
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    let mut type_info_for_generics = generics.clone();
+    type_info_for_generics.params.push(parse_quote!(T: #trait_));
+    let (type_info_for_impl_generics, _, _) = type_info_for_generics.split_for_impl();
+
+    // Spanned to the field, so that a missing associated function is reported on the field
+    // rather than on the macro invocation
+    let field_inits = field_idents.map(
+        |field| quote_spanned!(field.span()=> #field: <T as #trait_>::#field)
+    );
+
+    quote!(
+        impl #impl_generics #ident #type_generics #where_clause {
+            /// Creates the type-info for a type implementing the trait.
+            #vis const fn new<T: #trait_>() -> Self {
+                Self {
+                    #(#field_inits),*
+                }
+            }
+        }
+
+        impl #type_info_for_impl_generics #crate_::TypeInfoFor<T> for #ident #type_generics #where_clause {
+            const TYPE_INFO: Self = Self::new::<T>();
+        }
+    )
+}
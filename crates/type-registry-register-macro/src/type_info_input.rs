@@ -0,0 +1,67 @@
+use syn::{Data, DeriveInput, Fields, Ident, Path, Type};
+use syn::parse::{Parse, ParseStream};
+use crate::type_info_attribute::TypeInfoAttribute;
+
+/// The input to the TypeInfo derive: a struct with named function-pointer fields.
+pub(crate) struct TypeInfoInput {
+    input: DeriveInput,
+    trait_: Path
+}
+
+impl TypeInfoInput {
+    pub fn input(&self) -> &DeriveInput {
+        &self.input
+    }
+
+    pub fn trait_(&self) -> &Path {
+        &self.trait_
+    }
+
+    /// The names of the type-info's fields.
+    pub fn field_idents(&self) -> impl Iterator<Item=&Ident> {
+        let Data::Struct(data) = &self.input.data else { unreachable!("checked when parsing") };
+        data.fields.iter().map(|field| field.ident.as_ref().expect("checked when parsing"))
+    }
+}
+
+impl Parse for TypeInfoInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let input: DeriveInput = Parse::parse(input)?;
+
+        let data = match &input.data {
+            Data::Struct(data) => data,
+            _ => return Err(syn::Error::new_spanned(&input.ident, "TypeInfo can only be derived for structs"))
+        };
+
+        let fields = match &data.fields {
+            Fields::Named(fields) => fields,
+            other => return Err(syn::Error::new_spanned(other, "TypeInfo can only be derived for structs with named fields"))
+        };
+
+        for field in &fields.named {
+            if !matches!(field.ty, Type::BareFn(_)) {
+                return Err(syn::Error::new_spanned(&field.ty, "TypeInfo can only be derived for structs whose fields are all function pointers"))
+            }
+        }
+
+        let mut trait_ = None;
+        for attr in &input.attrs {
+            if !attr.path().is_ident("type_info") { continue }
+            if trait_.is_some() {
+                return Err(syn::Error::new_spanned(attr, "duplicate `type_info` attribute"))
+            }
+            trait_ = Some(attr.parse_args::<TypeInfoAttribute>()?.into_path());
+        }
+
+        let Some(trait_) = trait_ else {
+            return Err(syn::Error::new_spanned(&input.ident, "missing `#[type_info(trait = ...)]` attribute"))
+        };
+
+        Ok(
+            Self {
+                input,
+                trait_
+            }
+        )
+    }
+}
@@ -0,0 +1,105 @@
+use std::mem::discriminant;
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{Attribute, AttrStyle, Expr, Ident, Meta, Token};
+use syn::parse::{Parse, Parser, ParseStream};
+use syn::punctuated::Punctuated;
+use crate::crate_attribute::CrateAttribute;
+use crate::registration_option::RegistrationOption;
+
+/// An attribute of the form `#[type_registry(...)]`, which customises how an item is registered.
+pub(crate) enum TypeRegistryAttribute {
+    /// `#[type_registry(crate = ...)]`, overriding the path to the type-registry crate.
+    Crate(CrateAttribute),
+    /// `#[type_registry(skip)]`, opting an item out of [register_all](crate::register_all).
+    Skip(Ident),
+    /// `#[type_registry(init = ...)]`, overriding the type-info initialisation expression of an
+    /// item in [register_all](crate::register_all).
+    Init {
+        keyword: Ident,
+        equals: Token![=],
+        expr: Expr
+    },
+    /// `#[type_registry(key = ...)]` etc., setting a registration option of an item in
+    /// [register_all](crate::register_all) or a variant in
+    /// [register_variants](crate::register_variants).
+    Option(RegistrationOption)
+}
+
+impl TypeRegistryAttribute {
+    /// Removes all `#[type_registry(...)]` attributes from the given attributes, returning them
+    /// parsed. A single attribute can contain several comma-separated entries.
+    pub fn extract(attrs: &mut Vec<Attribute>) -> syn::Result<Vec<Self>> {
+        let mut our_attrs = Vec::new();
+
+        let extract = |attr: &Attribute| {
+            if discriminant(&attr.style) != discriminant(&AttrStyle::Outer) { return true }
+            let meta = match &attr.meta {
+                Meta::List(meta) => meta,
+                _ => return true
+            };
+            if !meta.path.is_ident("type_registry") { return true }
+            our_attrs.push(meta.tokens.clone());
+            false
+        };
+
+        attrs.retain(extract);
+
+        let mut parsed = Vec::new();
+        for attr in our_attrs {
+            let entries = Punctuated::<Self, Token![,]>::parse_terminated.parse2(attr)?;
+            parsed.extend(entries);
+        }
+
+        Ok(parsed)
+    }
+
+    /// The error to report when this attribute isn't supported in its position.
+    pub fn unexpected(&self) -> syn::Error {
+        syn::Error::new_spanned(self, "this `type_registry` attribute is not supported here")
+    }
+}
+
+impl Parse for TypeRegistryAttribute {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![crate]) {
+            return Ok(Self::Crate(Parse::parse(input)?))
+        }
+
+        let fork = input.fork();
+        let keyword: Ident = Parse::parse(&fork)?;
+        if keyword != "init" && RegistrationOption::peek(input) {
+            return Ok(Self::Option(Parse::parse(input)?))
+        }
+
+        let keyword: Ident = Parse::parse(input)?;
+        if keyword == "skip" {
+            Ok(Self::Skip(keyword))
+        } else if keyword == "init" {
+            Ok(
+                Self::Init {
+                    keyword,
+                    equals: Parse::parse(input)?,
+                    expr: Parse::parse(input)?
+                }
+            )
+        } else {
+            Err(syn::Error::new_spanned(keyword, "expected `crate`, `skip`, `init` or a registration option"))
+        }
+    }
+}
+
+impl ToTokens for TypeRegistryAttribute {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            TypeRegistryAttribute::Crate(attr) => attr.to_tokens(tokens),
+            TypeRegistryAttribute::Skip(keyword) => keyword.to_tokens(tokens),
+            TypeRegistryAttribute::Init { keyword, equals, expr } => {
+                keyword.to_tokens(tokens);
+                equals.to_tokens(tokens);
+                expr.to_tokens(tokens);
+            },
+            TypeRegistryAttribute::Option(option) => option.to_tokens(tokens)
+        }
+    }
+}
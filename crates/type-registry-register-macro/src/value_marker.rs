@@ -0,0 +1,41 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Expr, Ident, Path, Type, Visibility};
+
+/// Generates the marker type which stands in for a registered static or const, along with its
+/// implementation of `RegisteredValue`. As for functions, the marker is a braced struct with the
+/// same name as the item, so it occupies only the type namespace.
+pub(crate) fn value_marker(
+    crate_: &Path,
+    vis: &Visibility,
+    ident: &Ident,
+    ty: &Type,
+    is_static: bool
+) -> TokenStream {
+    // A const's value is copied into a static, so that its reference is 'static even if it
+    // can't be promoted
+    let value: Expr = if is_static {
+        syn::parse_quote!(&#ident)
+    } else {
+        syn::parse_quote!({
+            static VALUE: #ty = #ident;
+            &VALUE
+        })
+    };
+
+    quote!(
+        #[doc(hidden)]
+        #[allow(non_camel_case_types)]
+        #vis struct #ident {}
+
+        impl #crate_::RegisteredValue for #ident {
+            type Value = #ty;
+
+            const NAME: &'static str = ::core::stringify!(#ident);
+
+            fn value() -> &'static Self::Value {
+                #value
+            }
+        }
+    )
+}
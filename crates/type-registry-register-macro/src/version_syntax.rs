@@ -0,0 +1,20 @@
+use syn::LitStr;
+use type_registry_version_syntax::{parse_requirement, parse_version};
+
+/// Checks that a `version = "..."` option is a valid `MAJOR.MINOR.PATCH` version, mirroring
+/// `type_registry::Version`'s parsing.
+pub(crate) fn check_version(version: &LitStr) -> syn::Result<()> {
+    match parse_version(&version.value()) {
+        Some(_) => Ok(()),
+        None => Err(syn::Error::new_spanned(version, "invalid version, expected `MAJOR.MINOR.PATCH`, e.g. \"1.4.0\""))
+    }
+}
+
+/// Checks that a `requires = "..."` option is a valid version requirement, mirroring
+/// `type_registry::VersionReq`'s parsing.
+pub(crate) fn check_requirement(requires: &LitStr) -> syn::Result<()> {
+    match parse_requirement(&requires.value()) {
+        Some(_) => Ok(()),
+        None => Err(syn::Error::new_spanned(requires, "invalid version requirement, expected comma-separated comparisons, e.g. \">=1.4, <2\""))
+    }
+}
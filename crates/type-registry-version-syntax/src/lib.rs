@@ -0,0 +1,113 @@
+//! Parsing of the version syntax used by `type_registry::Version` and `type_registry::VersionReq`,
+//! shared with the `register` macro so that `version = "..."` and `requires = "..."` options can
+//! be checked at compile time. Versions are given as `(major, minor, patch)` triples.
+
+/// A version as a `(major, minor, patch)` triple.
+pub type VersionParts = (u64, u64, u64);
+
+/// Parses a version with possibly missing trailing parts, e.g. `1.4`.
+///
+/// E.g.:
+/// ```
+/// use type_registry_version_syntax::parse_partial;
+///
+/// assert_eq!(parse_partial("1.4"), Some((1, Some(4), None)));
+/// assert_eq!(parse_partial("1.04"), None);
+/// assert_eq!(parse_partial("1.4.0.0"), None);
+/// ```
+pub fn parse_partial(input: &str) -> Option<(u64, Option<u64>, Option<u64>)> {
+    let parse_part = |part: &str| -> Option<u64> {
+        // Like semver, disallow leading zeroes (and signs, which u64's FromStr would accept)
+        if part.is_empty() || !part.bytes().all(|byte| byte.is_ascii_digit()) || (part.len() > 1 && part.starts_with('0')) {
+            return None
+        }
+        part.parse().ok()
+    };
+
+    let mut parts = input.trim().split('.');
+    let major = parse_part(parts.next()?)?;
+    let minor = match parts.next() {
+        Some(part) => Some(parse_part(part)?),
+        None => None
+    };
+    let patch = match parts.next() {
+        Some(part) => Some(parse_part(part)?),
+        None => None
+    };
+    if parts.next().is_some() {
+        return None
+    }
+
+    Some((major, minor, patch))
+}
+
+/// Parses a full `MAJOR.MINOR.PATCH` version.
+pub fn parse_version(input: &str) -> Option<VersionParts> {
+    match parse_partial(input)? {
+        (major, Some(minor), Some(patch)) => Some((major, minor, patch)),
+        _ => None
+    }
+}
+
+/// Parses a single comparison of a version requirement, e.g. `>=1.4`, into the range
+/// `[lower, upper)` of versions it matches (unbounded where `None`). Follows Cargo's
+/// interpretation of the operators, and fails if a bound isn't representable.
+///
+/// E.g.:
+/// ```
+/// use type_registry_version_syntax::parse_comparison;
+///
+/// assert_eq!(parse_comparison("~1.4"), Some((Some((1, 4, 0)), Some((1, 5, 0)))));
+/// assert_eq!(parse_comparison("*"), Some((None, None)));
+/// assert_eq!(parse_comparison("^18446744073709551615"), None);
+/// ```
+pub fn parse_comparison(input: &str) -> Option<(Option<VersionParts>, Option<VersionParts>)> {
+    let input = input.trim();
+    if input == "*" {
+        return Some((None, None))
+    }
+
+    let operator_len = input.find(|c: char| c.is_ascii_digit()).unwrap_or(input.len());
+    let (operator, version) = input.split_at(operator_len);
+    let (major, minor, patch) = parse_partial(version)?;
+    // Parts may be as large as u64::MAX, so there may be no next version
+    let increment = |part: u64| part.checked_add(1);
+
+    let floor = (major, minor.unwrap_or(0), patch.unwrap_or(0));
+    // The version after all versions matching the partial version
+    let next = || -> Option<VersionParts> {
+        Some(match (minor, patch) {
+            (None, _) => (increment(major)?, 0, 0),
+            (Some(minor), None) => (major, increment(minor)?, 0),
+            (Some(minor), Some(patch)) => (major, minor, increment(patch)?)
+        })
+    };
+
+    let range = match operator.trim() {
+        ">=" => (Some(floor), None),
+        ">" => (Some(next()?), None),
+        "<=" => (None, Some(next()?)),
+        "<" => (None, Some(floor)),
+        "=" => (Some(floor), Some(next()?)),
+        "~" => match minor {
+            None => (Some(floor), Some((increment(major)?, 0, 0))),
+            Some(minor) => (Some(floor), Some((major, increment(minor)?, 0)))
+        },
+        "^" | "" => match (major, minor, patch) {
+            (0, None, _) => (Some(floor), Some((1, 0, 0))),
+            (0, Some(0), None) => (Some(floor), Some((0, 1, 0))),
+            (0, Some(0), Some(patch)) => (Some(floor), Some((0, 0, increment(patch)?))),
+            (0, Some(minor), _) => (Some(floor), Some((0, increment(minor)?, 0))),
+            (major, _, _) => (Some(floor), Some((increment(major)?, 0, 0)))
+        },
+        _ => return None
+    };
+
+    Some(range)
+}
+
+/// Parses a version requirement, a comma-separated list of comparisons (see [parse_comparison]),
+/// into the ranges of versions which must all contain a matching version.
+pub fn parse_requirement(input: &str) -> Option<Vec<(Option<VersionParts>, Option<VersionParts>)>> {
+    input.split(',').map(parse_comparison).collect()
+}
@@ -74,8 +74,9 @@
 //!
 //! E.g. similar to the example above, but with a `StringAnalyser` trait:
 //!
-//! ```
-//! use type_registry::{register, Registered, Registration, Registry, RegistryExt};
+#![cfg_attr(feature = "macro", doc = "```")]
+#![cfg_attr(not(feature = "macro"), doc = "```ignore")]
+//! use type_registry::{register, Registered, Registration, Registry, RegistryExt, TypeInfoFor};
 //!
 //! trait StringAnalyser: Registered<StringAnalyserRegistry> {
 //!     fn analyse(string: &str) -> Option<usize>;
@@ -85,12 +86,10 @@
 //!     analyse: fn(&str) -> Option<usize>
 //! }
 //!
-//! impl StringAnalyserTypeInfo {
-//!     pub const fn new<T: StringAnalyser>() -> Self {
-//!         Self {
-//!             analyse: T::analyse
-//!         }
-//!     }
+//! impl<T: StringAnalyser> TypeInfoFor<T> for StringAnalyserTypeInfo {
+//!     const TYPE_INFO: Self = Self {
+//!         analyse: T::analyse
+//!     };
 //! }
 //!
 //! struct StringAnalyserRegistry;
@@ -149,4 +148,4 @@ pub mod reexports {
 }
 
 #[cfg(feature = "macro")]
-pub use type_registry_register_macro::register;
+pub use type_registry_register_macro::{register, register_all, register_variants, registry_trait, Registry, TypeInfo};
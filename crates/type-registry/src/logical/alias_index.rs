@@ -0,0 +1,88 @@
+use std::any::type_name;
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+use crate::logical::index::Index;
+use crate::logical::key_index::KeyIndex;
+use crate::logical::registration_id::RegistrationId;
+use crate::logical::registry::Registry;
+use crate::logical::registry_entry::RegistryEntry;
+use crate::logical::registry_ext::RegistryExt;
+
+/// [Index] of the entries in a [registry](Registry) by the alternative keys (aliases) they were
+/// registered under. Aliases share a namespace with keys, so panics when built if an alias is
+/// also another entry's key or alias, just like duplicate keys (see [KeyIndex]). Key lookups (see
+/// [RegistryExt::lookup]) always build this index, so such a collision is reported by the first
+/// lookup, whatever its key.
+///
+/// E.g.:
+#[cfg_attr(feature = "macro", doc = "```")]
+#[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+/// use type_registry::{register, RegistrationId, Registry, RegistryExt};
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = ())]
+/// struct Formats;
+///
+/// #[register(Formats, key = "yaml", aliases = ["yml", "old_yaml"])]
+/// struct Yaml;
+///
+/// fn main() {
+///     let yaml = RegistrationId::<Formats>::of::<Yaml>();
+///     assert_eq!(Formats::get("yml").map(|entry| entry.registration_id()), Some(yaml));
+///
+///     let lookup = Formats::lookup("old_yaml").unwrap();
+///     assert_eq!(lookup.registration_id(), yaml);
+///     assert_eq!(lookup.alias(), Some("old_yaml"));
+///     assert!(!Formats::lookup("yaml").unwrap().is_alias());
+/// }
+/// ```
+///
+/// E.g. an alias which is also a key:
+#[cfg_attr(feature = "macro", doc = "```should_panic")]
+#[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+/// use type_registry::{register, Registry, RegistryExt};
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = ())]
+/// struct Formats;
+///
+/// #[register(Formats, key = "yaml", aliases = ["yml"])]
+/// struct Yaml;
+///
+/// #[register(Formats, key = "yml")]
+/// struct Yml;
+///
+/// fn main() {
+///     // Panics with "duplicate '...::Formats' key 'yml' for '...::Yml' and '...::Yaml'"
+///     Formats::get("yml");
+/// }
+/// ```
+pub struct AliasIndex;
+
+impl<R: Registry + ?Sized> Index<R> for AliasIndex {
+    type Storage = HashMap<&'static str, RegistrationId<R>>;
+
+    fn allocate() -> Self::Storage {
+        HashMap::new()
+    }
+
+    fn associate(
+        storage: &mut Self::Storage,
+        id: RegistrationId<R>,
+        entry: RegistryEntry<R>
+    ) -> ControlFlow<()> {
+        for alias in entry.aliases() {
+            let existing = storage.insert(alias, id)
+                .or_else(|| R::index::<KeyIndex>().get(alias).copied())
+                .filter(|existing| *existing != id);
+
+            if let Some(existing) = existing {
+                let registry = type_name::<R>();
+                let existing = existing.entry().raw().type_name();
+                let name = entry.raw().type_name();
+                panic!("duplicate '{registry}' key '{alias}' for '{existing}' and '{name}'")
+            }
+        }
+        ControlFlow::Continue(())
+    }
+}
@@ -0,0 +1,209 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::ControlFlow;
+use std::str::FromStr;
+use crate::logical::alias_index::AliasIndex;
+use crate::logical::incompatibility::Incompatibility;
+use crate::logical::index::Index;
+use crate::logical::key_index::KeyIndex;
+use crate::logical::key_lookup::KeyLookup;
+use crate::logical::registration_id::RegistrationId;
+use crate::logical::registration_order::RegistrationOrder;
+use crate::logical::registry::Registry;
+use crate::logical::registry_entry::RegistryEntry;
+use crate::logical::registry_ext::{report_use, RegistryExt};
+use crate::logical::version::Version;
+use crate::logical::version_req::VersionReq;
+
+/// The entries of a [registry](Registry) which are compatible with a particular host version,
+/// i.e. whose `requires = "..."` [requirement](VersionReq) (if any) the host version meets. Also
+/// reports which entries were dropped as incompatible, and why.
+///
+/// E.g.:
+#[cfg_attr(feature = "macro", doc = "```")]
+#[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+/// use type_registry::{register, Registry, RegistryExt, Version};
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = ())]
+/// struct Plugins;
+///
+/// #[register(Plugins, key = "thumbnails", version = "0.3.1", requires = ">=1.4")]
+/// struct Thumbnails;
+///
+/// #[register(Plugins, key = "sync", version = "2.0.0", requires = ">=2.1, <3")]
+/// struct Sync;
+///
+/// #[register(Plugins, key = "search")]
+/// struct Search;
+///
+/// fn main() {
+///     let compatible = Plugins::compatible_with(Version::new(1, 5, 0));
+///     assert_eq!(compatible.iter().count(), 2);
+///     assert_eq!(compatible.get("thumbnails").and_then(|entry| entry.version()), Some("0.3.1"));
+///     assert!(compatible.get("sync").is_none());
+///
+///     for (id, incompatibility) in compatible.dropped() {
+///         eprintln!("dropped '{}': {incompatibility}", id.entry().key().unwrap());
+///     }
+///     assert_eq!(compatible.dropped().len(), 1);
+/// }
+/// ```
+///
+/// Entries are filtered before overrides are resolved (see [IterAll](crate::IterAll)), so an
+/// entry overridden by an incompatible one is used in its place:
+#[cfg_attr(feature = "macro", doc = "```")]
+#[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+/// use type_registry::{register, RegistrationId, Registry, RegistryExt, Version};
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = ())]
+/// struct Services;
+///
+/// #[register(Services, key = "logger", weak)]
+/// struct DefaultLogger;
+///
+/// #[register(Services, key = "logger", requires = ">=2")]
+/// struct FileLogger;
+///
+/// fn main() {
+///     let default_logger = RegistrationId::<Services>::of::<DefaultLogger>();
+///     let file_logger = RegistrationId::<Services>::of::<FileLogger>();
+///
+///     let compatible = Services::compatible_with(Version::new(1, 5, 0));
+///     assert_eq!(compatible.get("logger").map(|entry| entry.registration_id()), Some(default_logger));
+///
+///     let compatible = Services::compatible_with(Version::new(2, 0, 0));
+///     assert_eq!(compatible.get("logger").map(|entry| entry.registration_id()), Some(file_logger));
+/// }
+/// ```
+///
+/// Versions and requirements are checked when registering, e.g. this fails to compile:
+#[cfg_attr(feature = "macro", doc = "```compile_fail")]
+#[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+/// use type_registry::{register, Registry};
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = ())]
+/// struct Plugins;
+///
+/// #[register(Plugins, key = "thumbnails", requires = ">=1.x")]
+/// struct Thumbnails;
+///
+/// fn main() {}
+/// ```
+pub struct Compatible<R: Registry + ?Sized> {
+    /// The host version.
+    host_version: Version,
+    /// The IDs of the compatible entries, in iteration order.
+    ids: Vec<RegistrationId<R>>,
+    /// The set of the compatible entries' IDs.
+    id_set: HashSet<RegistrationId<R>>,
+    /// The IDs of the compatible entries, by key (see [KeyIndex]).
+    keys: HashMap<&'static str, RegistrationId<R>>,
+    /// The IDs of the compatible entries, by alias (see [AliasIndex]).
+    aliases: HashMap<&'static str, RegistrationId<R>>,
+    /// The IDs of the incompatible entries, and why they are incompatible.
+    dropped: Vec<(RegistrationId<R>, Incompatibility)>
+}
+
+impl<R: Registry + ?Sized> Compatible<R> {
+    /// Sorts the entries of a [registry](Registry) by whether they are compatible with the given
+    /// host version.
+    pub fn new(host_version: Version) -> Self {
+        let mut ids = Vec::new();
+        let mut dropped = Vec::new();
+
+        for (id, entry) in R::iter_all() {
+            match Self::incompatibility(&entry, host_version) {
+                Some(incompatibility) => dropped.push((id, incompatibility)),
+                None => ids.push(id)
+            }
+        }
+
+        // Overrides are only resolved between the compatible entries, so that e.g. a weak fallback
+        // is used in place of an incompatible entry with the same key.
+        let raw_indices: Vec<usize> = ids.iter().map(|id| id.raw().index()).collect();
+        let overriders = RegistrationOrder::overriders(&raw_indices);
+        ids.retain(|id| !overriders.contains_key(&id.raw().index()));
+        ids.sort_by_key(|id| RegistrationOrder::sort_key(id.raw().index()));
+
+        let mut compatible = Self {
+            host_version,
+            id_set: ids.iter().copied().collect(),
+            ids,
+            keys: HashMap::new(),
+            aliases: HashMap::new(),
+            dropped
+        };
+        compatible.keys = compatible.index::<KeyIndex>();
+        compatible.aliases = compatible.index::<AliasIndex>();
+        compatible
+    }
+
+    fn incompatibility(entry: &RegistryEntry<R>, host_version: Version) -> Option<Incompatibility> {
+        let requires = entry.requires()?;
+        match VersionReq::from_str(requires) {
+            Ok(requirement) if requirement.matches(&host_version) => None,
+            Ok(_) => Some(Incompatibility::Unsatisfied { requires, host_version }),
+            Err(error) => Some(Incompatibility::InvalidRequirement { requires, error })
+        }
+    }
+
+    /// The host version the entries are compatible with.
+    pub fn host_version(&self) -> Version {
+        self.host_version
+    }
+
+    /// Iterates over the compatible entries, in the [registry's](Registry)
+    /// [iteration](crate::Iter) order.
+    pub fn iter(&self) -> impl Iterator<Item=(RegistrationId<R>, RegistryEntry<R>)> + '_ {
+        self.ids.iter().map(|id| (*id, id.entry()))
+    }
+
+    /// Whether the identified entry is compatible.
+    pub fn contains(&self, id: RegistrationId<R>) -> bool {
+        self.id_set.contains(&id)
+    }
+
+    /// Gets the compatible entry registered under the given key or alias, if any (see
+    /// [RegistryExt::get]).
+    pub fn get(&self, key: &str) -> Option<RegistryEntry<R>> {
+        self.lookup(key).map(|lookup| lookup.entry())
+    }
+
+    /// Looks up the compatible entry registered under the given key or alias, if any (see
+    /// [RegistryExt::lookup]). If another entry with the same key overrides it, but is
+    /// incompatible, the compatible entry is found instead.
+    pub fn lookup(&self, key: &str) -> Option<KeyLookup<R>> {
+        let lookup = match self.keys.get(key) {
+            Some(id) => KeyLookup::new(*id, None),
+            None => {
+                let (alias, id) = self.aliases.get_key_value(key)?;
+                KeyLookup::new(*id, Some(*alias))
+            }
+        };
+
+        report_use(lookup.registration_id());
+        Some(lookup)
+    }
+
+    /// Builds an [index](Index) over only the compatible entries.
+    pub fn index<I: Index<R>>(&self) -> I::Storage {
+        let mut storage = I::allocate();
+
+        for (id, entry) in self.iter() {
+            match I::associate(&mut storage, id, entry) {
+                ControlFlow::Continue(_) => continue,
+                ControlFlow::Break(_) => break
+            }
+        }
+
+        storage
+    }
+
+    /// The incompatible entries which were dropped (including those which were overridden by
+    /// another entry with the same key), and why, in [IterAll](crate::IterAll) order.
+    pub fn dropped(&self) -> &[(RegistrationId<R>, Incompatibility)] {
+        &self.dropped
+    }
+}
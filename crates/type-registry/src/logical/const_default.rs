@@ -0,0 +1,53 @@
+/// A [Default] which can be used in const contexts. All types which implement this are
+/// [type-info](crate::TypeInfoFor) for every type, so it's useful for
+/// [type-info](crate::Registry::TypeInfo) which doesn't depend on the registered type.
+///
+/// Implemented for the primitive types (with the same values as [Default]), [Option] (as `None`),
+/// `&'static str` and `&'static [T]` (as empty), and arrays of implementing types. E.g.:
+/// ```
+/// use type_registry::{ConstDefault, TypeInfoFor};
+///
+/// const LIMITS: [u8; 2] = ConstDefault::DEFAULT;
+/// const NAME: Option<&'static str> = <Option<&'static str> as TypeInfoFor<u16>>::TYPE_INFO;
+///
+/// fn main() {
+///     assert_eq!(LIMITS, [0, 0]);
+///     assert_eq!(NAME, None);
+/// }
+/// ```
+pub trait ConstDefault {
+    /// The default value.
+    const DEFAULT: Self;
+}
+
+macro_rules! impl_const_default {
+    ($($type:ty = $default:expr),+ $(,)?) => {
+        $(
+            impl ConstDefault for $type {
+                const DEFAULT: Self = $default;
+            }
+        )+
+    };
+}
+
+impl_const_default!(
+    () = (),
+    bool = false,
+    char = '\0',
+    u8 = 0, u16 = 0, u32 = 0, u64 = 0, u128 = 0, usize = 0,
+    i8 = 0, i16 = 0, i32 = 0, i64 = 0, i128 = 0, isize = 0,
+    f32 = 0.0, f64 = 0.0,
+    &'static str = ""
+);
+
+impl<T> ConstDefault for Option<T> {
+    const DEFAULT: Self = None;
+}
+
+impl<T: 'static> ConstDefault for &'static [T] {
+    const DEFAULT: Self = &[];
+}
+
+impl<T: ConstDefault, const N: usize> ConstDefault for [T; N] {
+    const DEFAULT: Self = [T::DEFAULT; N];
+}
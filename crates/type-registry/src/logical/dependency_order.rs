@@ -0,0 +1,121 @@
+use std::any::{type_name, Any};
+use std::collections::BTreeSet;
+use std::sync::OnceLock;
+use generic_static::StaticTypeMap;
+use crate::logical::registration_id::RegistrationId;
+use crate::logical::registry::Registry;
+use crate::logical::registry_ext::RegistryExt;
+use crate::raw::{RegistryEntry as RawRegistryEntry, TypeRef};
+
+/// Gets the [IDs](RegistrationId) of the entries of a [registry](Registry), sorted so that each
+/// entry comes after the types it was registered `after` and before the types it was registered
+/// `before`. Entries which aren't constrained relative to each other keep their
+/// [iteration](crate::Iter) order. Computed once per registry, panicking if the constraints refer
+/// to types which aren't registered or form a cycle.
+pub(crate) fn dependency_order<R: Registry + ?Sized>() -> &'static [RegistrationId<R>] {
+    static ORDER_TYPE_MAP: OnceLock<StaticTypeMap<Box<dyn Any + Send + Sync>>> = OnceLock::new();
+    let order_type_map = ORDER_TYPE_MAP.get_or_init(StaticTypeMap::new);
+
+    let any = order_type_map.call_once::<fn(R), _>(|| Box::new(sort::<R>()));
+
+    let order: &Vec<RegistrationId<R>> = any.downcast_ref().expect("order is associated to registry");
+    order
+}
+
+/// Topologically sorts the entries of a [registry](Registry) by their ordering constraints,
+/// always picking the earliest available entry in iteration order next.
+fn sort<R: Registry + ?Sized>() -> Vec<RegistrationId<R>> {
+    let ids: Vec<RegistrationId<R>> = R::iter().map(|(id, _entry)| id).collect();
+
+    // successors[i] are the registry indices of the entries which must come after entry i. A
+    // constraint relative to the entry an entry overrode resolves to the entry itself, so is ignored
+    let mut successors = vec![Vec::new(); ids.len()];
+    for id in &ids {
+        let raw = id.entry().raw();
+        for after in raw.after() {
+            let other = resolve(*id, after, "after");
+            if other != id.index() {
+                successors[other].push(id.index());
+            }
+        }
+        for before in raw.before() {
+            let other = resolve(*id, before, "before");
+            if other != id.index() {
+                successors[id.index()].push(other);
+            }
+        }
+    }
+
+    let mut predecessor_counts = vec![0usize; ids.len()];
+    for successor in successors.iter().flatten() {
+        predecessor_counts[*successor] += 1;
+    }
+
+    let mut ready: BTreeSet<usize> = (0..ids.len()).filter(|index| predecessor_counts[*index] == 0).collect();
+    let mut order = Vec::with_capacity(ids.len());
+    while let Some(index) = ready.pop_first() {
+        order.push(ids[index]);
+        for successor in &successors[index] {
+            predecessor_counts[*successor] -= 1;
+            if predecessor_counts[*successor] == 0 {
+                ready.insert(*successor);
+            }
+        }
+    }
+
+    if order.len() < ids.len() {
+        report_cycle(&ids, &successors, &predecessor_counts)
+    }
+
+    order
+}
+
+/// Gets the registry index of the type an entry was registered to be ordered relative to (or, if
+/// that type's entry was overridden, of the entry which overrode it), panicking if it isn't
+/// registered.
+fn resolve<R: Registry + ?Sized>(id: RegistrationId<R>, type_ref: &TypeRef, relation: &str) -> usize {
+    match R::index::<RawRegistryEntry>().get(&type_ref.type_id()) {
+        Some(other) => other.overridden_by().unwrap_or(*other).index(),
+        None => {
+            let registry = type_name::<R>();
+            let name = id.entry().type_name();
+            let other = type_ref.type_name();
+            panic!("'{registry}' registration '{name}' must come {relation} '{other}', which isn't registered to '{registry}'")
+        }
+    }
+}
+
+/// Panics with a cycle among the entries which couldn't be sorted, i.e. those which still have
+/// predecessors. Each of these has a predecessor which couldn't be sorted either, so following
+/// predecessors must eventually revisit an entry.
+fn report_cycle<R: Registry + ?Sized>(
+    ids: &[RegistrationId<R>],
+    successors: &[Vec<usize>],
+    predecessor_counts: &[usize]
+) -> ! {
+    let unsorted = |index: usize| predecessor_counts[index] > 0;
+    let unsorted_predecessor = |index: usize| (0..ids.len())
+        .find(|predecessor| unsorted(*predecessor) && successors[*predecessor].contains(&index))
+        .expect("unsorted entry has unsorted predecessor");
+
+    let start = (0..ids.len()).find(|index| unsorted(*index)).expect("some entry is unsorted");
+    let mut path = vec![start];
+    let cycle_start = loop {
+        let predecessor = unsorted_predecessor(*path.last().expect("path is non-empty"));
+        if let Some(position) = path.iter().position(|index| *index == predecessor) {
+            break position
+        }
+        path.push(predecessor);
+    };
+
+    // The path follows predecessors, so reverse the cycle to list each entry before its successor
+    let mut cycle: Vec<String> = path[cycle_start..].iter()
+        .rev()
+        .map(|index| format!("'{}'", ids[*index].entry().type_name()))
+        .collect();
+    cycle.push(cycle[0].clone());
+
+    let registry = type_name::<R>();
+    let cycle = cycle.join(" -> ");
+    panic!("cycle in the ordering of '{registry}' registrations (each must come before the next): {cycle}")
+}
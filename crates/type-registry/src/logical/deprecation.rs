@@ -0,0 +1,80 @@
+use std::fmt::{Display, Formatter};
+
+/// Marks a registration as deprecated, with optionally the version since which it has been
+/// deprecated and a note (e.g. what to use instead). Given to the [register](crate::register)
+/// macro as `deprecated(since = "...", note = "...")`.
+///
+/// Lookups by key or name report uses of deprecated entries to
+/// [Registry::on_deprecated_use](crate::Registry::on_deprecated_use). E.g.:
+#[cfg_attr(feature = "macro", doc = "```")]
+#[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use type_registry::{register, Deprecation, Registry, RegistryEntry, RegistryExt};
+///
+/// static DEPRECATED_USES: AtomicUsize = AtomicUsize::new(0);
+///
+/// fn warn(entry: RegistryEntry<Formats>, deprecation: &Deprecation) {
+///     eprintln!("warning: '{}' is {deprecation}", entry.key().unwrap());
+///     DEPRECATED_USES.fetch_add(1, Ordering::Relaxed);
+/// }
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = (), on_deprecated_use = warn)]
+/// struct Formats;
+///
+/// #[register(Formats, key = "json", deprecated(since = "2.3", note = "use json5"))]
+/// struct Json;
+///
+/// #[register(Formats, key = "json5")]
+/// struct Json5;
+///
+/// fn main() {
+///     let lookup = Formats::lookup("json").unwrap();
+///     let deprecation = lookup.deprecation().unwrap();
+///     assert_eq!(deprecation.since(), Some("2.3"));
+///     assert_eq!(deprecation.to_string(), "deprecated since 2.3: use json5");
+///
+///     assert!(Formats::get("json5").unwrap().deprecation().is_none());
+///     assert_eq!(DEPRECATED_USES.load(Ordering::Relaxed), 1);
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Deprecation {
+    /// The version since which the registration has been deprecated, if given.
+    since: Option<&'static str>,
+    /// A note about the deprecation, if given.
+    note: Option<&'static str>
+}
+
+impl Deprecation {
+    /// Creates a deprecation.
+    pub const fn new(since: Option<&'static str>, note: Option<&'static str>) -> Self {
+        Self {
+            since,
+            note
+        }
+    }
+
+    /// The version since which the registration has been deprecated, if given.
+    pub fn since(&self) -> Option<&'static str> {
+        self.since
+    }
+
+    /// A note about the deprecation, if given.
+    pub fn note(&self) -> Option<&'static str> {
+        self.note
+    }
+}
+
+impl Display for Deprecation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("deprecated")?;
+        if let Some(since) = self.since {
+            f.write_fmt(format_args!(" since {since}"))?;
+        }
+        if let Some(note) = self.note {
+            f.write_fmt(format_args!(": {note}"))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+use crate::logical::index::Index;
+use crate::logical::registration_id::RegistrationId;
+use crate::logical::registry::Registry;
+use crate::logical::registry_entry::RegistryEntry;
+
+/// [Index] of the entries in a [registry](Registry) by the explicit numeric IDs they were
+/// registered with. Entries without explicit IDs are not indexed. Explicit IDs are checked to be
+/// unique when the registry is first accessed.
+///
+/// E.g.:
+#[cfg_attr(feature = "macro", doc = "```")]
+#[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+/// use type_registry::{register, RegistrationId, Registry};
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = ())]
+/// struct Messages;
+///
+/// #[register(Messages, id = 17)]
+/// struct Ping;
+///
+/// #[register(Messages, id = 3)]
+/// struct Pong;
+///
+/// fn main() {
+///     let ping = RegistrationId::<Messages>::of::<Ping>();
+///     assert_eq!(ping.explicit_id(), Some(17));
+///     assert_eq!(RegistrationId::<Messages>::from_explicit(17), Some(ping));
+///     assert!(RegistrationId::<Messages>::from_explicit(4).is_none());
+/// }
+/// ```
+pub struct ExplicitIdIndex;
+
+impl<R: Registry + ?Sized> Index<R> for ExplicitIdIndex {
+    type Storage = HashMap<u32, RegistrationId<R>>;
+
+    fn allocate() -> Self::Storage {
+        HashMap::new()
+    }
+
+    fn associate(
+        storage: &mut Self::Storage,
+        id: RegistrationId<R>,
+        entry: RegistryEntry<R>
+    ) -> ControlFlow<()> {
+        if let Some(explicit_id) = entry.explicit_id() {
+            storage.insert(explicit_id, id);
+        }
+        ControlFlow::Continue(())
+    }
+}
@@ -0,0 +1,66 @@
+use crate::logical::registered_fn::RegisteredFn;
+use crate::logical::type_info_for::TypeInfoFor;
+
+/// [Type-info](crate::Registry::TypeInfo) for registries of functions, holding a pointer to the
+/// registered function along with its name.
+///
+/// E.g.:
+#[cfg_attr(feature = "macro", doc = "```")]
+#[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+/// use type_registry::{register, FnInfo, Registry, RegistryExt};
+///
+/// struct Migrations;
+///
+/// impl Registry for Migrations {
+///     type TypeInfo = FnInfo<fn(&mut Vec<&'static str>)>;
+///
+///     fn name() -> &'static str {
+///         "Migrations"
+///     }
+/// }
+///
+/// #[register(Migrations)]
+/// fn add_users_table(tables: &mut Vec<&'static str>) {
+///     tables.push("users")
+/// }
+///
+/// fn main() {
+///     let mut tables = Vec::new();
+///     for (_id, entry) in Migrations::iter() {
+///         assert_eq!(entry.type_info().name(), "add_users_table");
+///         (entry.type_info().function())(&mut tables);
+///     }
+///     assert_eq!(tables, ["users"]);
+/// }
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct FnInfo<F> {
+    /// The name of the registered function.
+    name: &'static str,
+    /// Pointer to the registered function.
+    function: F
+}
+
+impl<F: Copy> FnInfo<F> {
+    /// Creates the information about a [registered function](RegisteredFn).
+    pub const fn new<T: RegisteredFn<Fn = F>>() -> Self {
+        Self {
+            name: T::NAME,
+            function: T::FN
+        }
+    }
+
+    /// The name of the registered function.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Pointer to the registered function.
+    pub const fn function(&self) -> F {
+        self.function
+    }
+}
+
+impl<F: Copy + Sync + 'static, T: RegisteredFn<Fn = F>> TypeInfoFor<T> for FnInfo<F> {
+    const TYPE_INFO: Self = Self::new::<T>();
+}
@@ -0,0 +1,35 @@
+use std::fmt::{Display, Formatter};
+use crate::logical::parse_version_error::ParseVersionError;
+use crate::logical::version::Version;
+
+/// Why a registered type isn't [compatible](crate::RegistryExt::compatible_with) with a host.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Incompatibility {
+    /// The type requires a host version which the host doesn't have.
+    Unsatisfied {
+        /// The type's requirement on the host version.
+        requires: &'static str,
+        /// The host's version.
+        host_version: Version
+    },
+    /// The type's requirement on the host version is invalid.
+    InvalidRequirement {
+        /// The type's requirement on the host version.
+        requires: &'static str,
+        /// The error parsing the requirement.
+        error: ParseVersionError
+    }
+}
+
+impl Display for Incompatibility {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Incompatibility::Unsatisfied { requires, host_version } => f.write_fmt(
+                format_args!("requires host version '{requires}', but the host version is {host_version}")
+            ),
+            Incompatibility::InvalidRequirement { error, .. } => f.write_fmt(
+                format_args!("has an invalid host version requirement: {error}")
+            )
+        }
+    }
+}
@@ -4,7 +4,8 @@ use std::ops::ControlFlow;
 use crate::logical::registration_id::RegistrationId;
 use crate::logical::registry::Registry;
 use crate::logical::registry_entry::RegistryEntry;
-use crate::raw::{RegistryEntry as RawRegistryEntry};
+use crate::logical::registration_order::RegistrationOrder;
+use crate::raw::{REGISTRY, RegistryEntry as RawRegistryEntry};
 
 /// A static index over a [registry](Registry).
 pub trait Index<R: Registry + ?Sized>: 'static {
@@ -25,11 +26,21 @@ pub trait Index<R: Registry + ?Sized>: 'static {
     ) -> ControlFlow<()>;
 }
 
+/// Indexes the [IDs](RegistrationId) of registered types by their [TypeId], including those of
+/// overridden entries.
 impl<R: Registry + ?Sized> Index<R> for RawRegistryEntry {
     type Storage = HashMap<TypeId, RegistrationId<R>>;
 
     fn allocate() -> Self::Storage {
-        HashMap::new()
+        let order = RegistrationOrder::of::<R>();
+        order.entries()
+            .iter()
+            .enumerate()
+            .skip(order.active().len())
+            .map(|(registry_index, raw_index)| {
+                (REGISTRY[*raw_index].type_id(), RegistrationId::new(*raw_index, registry_index))
+            })
+            .collect()
     }
 
     fn associate(
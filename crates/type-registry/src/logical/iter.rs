@@ -1,15 +1,51 @@
 use std::marker::PhantomData;
 use crate::logical::registry::Registry;
 use crate::logical::registration_id::RegistrationId;
+use crate::logical::registration_order::RegistrationOrder;
 use crate::logical::registry_entry::RegistryEntry;
-use crate::raw::{REGISTRY, RegistryEntry as RawRegistryEntry, RegistryId};
+use crate::raw::REGISTRY;
 
 /// Iterator over the entries in a [registry](Registry).
+///
+/// Entries are yielded in an order which only depends on the registered types (sorted by
+/// [stable name](RegistryEntry::stable_name), then by key), not on the order the linker happened
+/// to place them in or on the compiler version, so iteration order and
+/// [registry indices](RegistrationId::index) are the same across builds of the same source.
+/// Entries which were overridden by another entry with the same key are skipped (see
+/// [IterAll](crate::IterAll)).
+///
+/// E.g.:
+#[cfg_attr(feature = "macro", doc = "```")]
+#[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+/// use type_registry::{register, Registry, RegistryExt};
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = ())]
+/// struct Commands;
+///
+/// #[register(Commands)]
+/// struct Zip;
+///
+/// #[register(Commands)]
+/// struct Add;
+///
+/// #[register(Commands)]
+/// struct Mv;
+///
+/// fn main() {
+///     let names: Vec<_> = Commands::iter()
+///         .map(|(_id, entry)| entry.type_name().rsplit("::").next().unwrap())
+///         .collect();
+///     assert_eq!(names, ["Add", "Mv", "Zip"]);
+///
+///     for (index, (id, _entry)) in Commands::iter().enumerate() {
+///         assert_eq!(id.index(), index);
+///     }
+/// }
+/// ```
 pub struct Iter<R: Registry + ?Sized> {
-    /// The iterator over all [entries](RawRegistryEntry) across all [registries](Registry).
-    raw_iter: std::iter::Enumerate<std::slice::Iter<'static, RawRegistryEntry>>,
-    /// Keeps track of the next entry's position in its logical [registry](Registry).
-    next_index: usize,
+    /// The iterator over the raw indices of the [registry's](Registry) entries, in order.
+    raw_indices: std::iter::Enumerate<std::slice::Iter<'static, usize>>,
     /// Marker of the [registry](Registry) being iterated over.
     registry: PhantomData<fn(R)>,
 }
@@ -19,29 +55,33 @@ impl<R: Registry + ?Sized> Iter<R> {
     /// [IDs](RegistrationId)) in a [registry](Registry).
     pub fn new() -> Self {
         Self {
-            raw_iter: REGISTRY.iter().enumerate(),
-            next_index: 0,
+            raw_indices: RegistrationOrder::of::<R>().active().iter().enumerate(),
             registry: PhantomData
         }
     }
 }
 
+impl<R: Registry + ?Sized> Default for Iter<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<R: Registry + ?Sized> Iterator for Iter<R> {
     type Item = (RegistrationId<R>, RegistryEntry<R>);
 
     fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            let (raw_index, next_raw) = self.raw_iter.next()?;
-            
-            if next_raw.registry_id() == RegistryId::of::<R>() {
-                let registry_index = self.next_index;
-                self.next_index += 1;
-                
-                return Some((
-                    RegistrationId::new(raw_index, registry_index),
-                    RegistryEntry::new(next_raw)
-                ))
-            }
-        }
+        let (registry_index, raw_index) = self.raw_indices.next()?;
+
+        Some((
+            RegistrationId::new(*raw_index, registry_index),
+            RegistryEntry::new(&REGISTRY[*raw_index])
+        ))
     }
-}
\ No newline at end of file
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.raw_indices.size_hint()
+    }
+}
+
+impl<R: Registry + ?Sized> ExactSizeIterator for Iter<R> {}
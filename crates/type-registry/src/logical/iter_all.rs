@@ -0,0 +1,91 @@
+use std::marker::PhantomData;
+use crate::logical::registry::Registry;
+use crate::logical::registration_id::RegistrationId;
+use crate::logical::registration_order::RegistrationOrder;
+use crate::logical::registry_entry::RegistryEntry;
+use crate::raw::REGISTRY;
+
+/// Iterator over all the entries in a [registry](Registry), including those which were overridden
+/// by another entry with the same key. The active entries are yielded first, in the same order as
+/// [Iter](crate::Iter), followed by the overridden ones, each with its own [ID](RegistrationId)
+/// (see [RegistrationId::overridden_by]).
+///
+/// Of the entries sharing a key, the one with the highest `priority` overrides the others, and
+/// `weak` entries are overridden by any entry which isn't weak. E.g.:
+#[cfg_attr(feature = "macro", doc = "```")]
+#[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+/// use type_registry::{register, RegistrationId, Registry, RegistryExt};
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = ())]
+/// struct Services;
+///
+/// // E.g. in a library
+/// #[register(Services, key = "logger", weak)]
+/// struct DefaultLogger;
+///
+/// // E.g. in an application
+/// #[register(Services, key = "logger", priority = 100)]
+/// struct FileLogger;
+///
+/// #[register(Services, key = "logger")]
+/// struct ConsoleLogger;
+///
+/// fn main() {
+///     let file_logger = RegistrationId::<Services>::of::<FileLogger>();
+///     assert_eq!(Services::get("logger").map(|entry| entry.registration_id()), Some(file_logger));
+///     assert_eq!(Services::iter().count(), 1);
+///
+///     let default_logger = RegistrationId::<Services>::of::<DefaultLogger>();
+///     assert_ne!(default_logger, file_logger);
+///     assert_eq!(default_logger.overridden_by(), Some(file_logger));
+///     assert_eq!(file_logger.overridden_by(), None);
+///
+///     let overridden = Services::iter_all()
+///         .filter(|(id, _entry)| id.overridden_by() == Some(file_logger))
+///         .count();
+///     assert_eq!(overridden, 2);
+/// }
+/// ```
+pub struct IterAll<R: Registry + ?Sized> {
+    /// The iterator over the raw indices of all the [registry's](Registry) entries, in order.
+    raw_indices: std::iter::Enumerate<std::slice::Iter<'static, usize>>,
+    /// Marker of the [registry](Registry) being iterated over.
+    registry: PhantomData<fn(R)>,
+}
+
+impl<R: Registry + ?Sized> IterAll<R> {
+    /// Creates a new iterator over all the [entries](RegistryEntry) (and their associated
+    /// [IDs](RegistrationId)) in a [registry](Registry).
+    pub fn new() -> Self {
+        Self {
+            raw_indices: RegistrationOrder::of::<R>().entries().iter().enumerate(),
+            registry: PhantomData
+        }
+    }
+}
+
+impl<R: Registry + ?Sized> Default for IterAll<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: Registry + ?Sized> Iterator for IterAll<R> {
+    type Item = (RegistrationId<R>, RegistryEntry<R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (registry_index, raw_index) = self.raw_indices.next()?;
+
+        Some((
+            RegistrationId::new(*raw_index, registry_index),
+            RegistryEntry::new(&REGISTRY[*raw_index])
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.raw_indices.size_hint()
+    }
+}
+
+impl<R: Registry + ?Sized> ExactSizeIterator for IterAll<R> {}
@@ -0,0 +1,161 @@
+use std::marker::PhantomData;
+use crate::logical::dependency_order::dependency_order;
+use crate::logical::registry::Registry;
+use crate::logical::registration_id::RegistrationId;
+use crate::logical::registry_entry::RegistryEntry;
+
+/// Iterator over the entries in a [registry](Registry), respecting the types' ordering
+/// constraints: each entry comes after the types it was registered `after` and before the types
+/// it was registered `before`. Entries which aren't constrained relative to each other keep their
+/// [iteration](crate::Iter) order. A constraint referring to a type whose entry was overridden
+/// (see [IterAll](crate::IterAll)) applies to the entry which overrode it instead.
+///
+/// Panics (when the order is first computed) if the constraints refer to types which aren't
+/// registered to the registry or form a cycle. E.g.:
+#[cfg_attr(feature = "macro", doc = "```")]
+#[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+/// use type_registry::{register, Registry, RegistryExt};
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = ())]
+/// struct Middleware;
+///
+/// #[register(Middleware, before = [Routing])]
+/// struct Auth;
+///
+/// #[register(Middleware, after = [Auth], before = [Logging])]
+/// struct Routing;
+///
+/// #[register(Middleware)]
+/// struct Logging;
+///
+/// fn main() {
+///     let names: Vec<_> = Middleware::iter_ordered()
+///         .map(|(_id, entry)| entry.type_name().rsplit("::").next().unwrap())
+///         .collect();
+///     assert_eq!(names, ["Auth", "Routing", "Logging"]);
+/// }
+/// ```
+///
+/// E.g. with an overridden entry:
+#[cfg_attr(feature = "macro", doc = "```")]
+#[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+/// use type_registry::{register, Registry, RegistryExt};
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = ())]
+/// struct Services;
+///
+/// #[register(Services, key = "logger", weak)]
+/// struct DefaultLogger;
+///
+/// #[register(Services, key = "logger")]
+/// struct FileLogger;
+///
+/// #[register(Services, before = [Auth])]
+/// struct Metrics;
+///
+/// #[register(Services, after = [DefaultLogger])]
+/// struct Auth;
+///
+/// fn main() {
+///     let names: Vec<_> = Services::iter_ordered()
+///         .map(|(_id, entry)| entry.type_name().rsplit("::").next().unwrap())
+///         .collect();
+///     assert_eq!(names, ["FileLogger", "Metrics", "Auth"]);
+/// }
+/// ```
+///
+/// A cycle is reported along with the types which form it:
+#[cfg_attr(feature = "macro", doc = "```should_panic")]
+#[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+/// use std::panic::{catch_unwind, resume_unwind};
+/// use type_registry::{register, Registry, RegistryExt};
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = ())]
+/// struct Middleware;
+///
+/// #[register(Middleware, before = [Routing])]
+/// struct Auth;
+///
+/// #[register(Middleware, before = [Auth])]
+/// struct Routing;
+///
+/// fn main() {
+///     // Panics with "cycle in the ordering of '...::Middleware' registrations (each must come
+///     // before the next): '...::Auth' -> '...::Routing' -> '...::Auth'"
+///     if let Err(error) = catch_unwind(|| Middleware::iter_ordered().count()) {
+///         let message = error.downcast_ref::<String>().map(String::as_str).unwrap_or_default();
+///         if message.starts_with("cycle") && message.contains("::Auth' -> '") && message.contains("::Routing'") {
+///             resume_unwind(error)
+///         }
+///     }
+/// }
+/// ```
+///
+/// As is a constraint referring to a type which isn't registered to the registry:
+#[cfg_attr(feature = "macro", doc = "```should_panic")]
+#[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+/// use std::panic::{catch_unwind, resume_unwind};
+/// use type_registry::{register, Registry, RegistryExt};
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = ())]
+/// struct Middleware;
+///
+/// struct Cache;
+///
+/// #[register(Middleware, after = [Cache])]
+/// struct Routing;
+///
+/// fn main() {
+///     // Panics with "'...::Middleware' registration '...::Routing' must come after
+///     // '...::Cache', which isn't registered to '...::Middleware'"
+///     if let Err(error) = catch_unwind(|| Middleware::iter_ordered().count()) {
+///         let message = error.downcast_ref::<String>().map(String::as_str).unwrap_or_default();
+///         if message.contains("::Routing' must come after '") && message.contains("::Cache', which isn't registered") {
+///             resume_unwind(error)
+///         }
+///     }
+/// }
+/// ```
+pub struct IterOrdered<R: Registry + ?Sized> {
+    /// The iterator over the [IDs](RegistrationId) of the [registry's](Registry) entries, in
+    /// order.
+    ids: std::slice::Iter<'static, RegistrationId<R>>,
+    /// Marker of the [registry](Registry) being iterated over.
+    registry: PhantomData<fn(R)>,
+}
+
+impl<R: Registry + ?Sized> IterOrdered<R> {
+    /// Creates a new iterator over the [entries](RegistryEntry) (and their associated
+    /// [IDs](RegistrationId)) in a [registry](Registry), respecting their ordering constraints.
+    pub fn new() -> Self {
+        Self {
+            ids: dependency_order::<R>().iter(),
+            registry: PhantomData
+        }
+    }
+}
+
+impl<R: Registry + ?Sized> Default for IterOrdered<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: Registry + ?Sized> Iterator for IterOrdered<R> {
+    type Item = (RegistrationId<R>, RegistryEntry<R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let id = *self.ids.next()?;
+        Some((id, id.entry()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ids.size_hint()
+    }
+}
+
+impl<R: Registry + ?Sized> ExactSizeIterator for IterOrdered<R> {}
@@ -0,0 +1,84 @@
+use std::any::type_name;
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+use crate::logical::index::Index;
+use crate::logical::registration_id::RegistrationId;
+use crate::logical::registry::Registry;
+use crate::logical::registry_entry::RegistryEntry;
+
+/// [Index] of the entries in a [registry](Registry) by the keys they were registered under.
+/// Entries without keys are not indexed, nor are entries overridden by a higher-priority entry
+/// with the same key (see [IterAll](crate::IterAll)). Panics, when first built, if two entries
+/// share a key and have the same priority.
+///
+/// E.g.:
+#[cfg_attr(feature = "macro", doc = "```")]
+#[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+/// use type_registry::{register, Registry, RegistryExt};
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = &'static str)]
+/// struct Formats;
+///
+/// #[register(Formats("application/json"), key = "json")]
+/// struct Json;
+///
+/// #[register(Formats("application/yaml"), key = "yaml")]
+/// struct Yaml;
+///
+/// fn main() {
+///     assert_eq!(Formats::get("json").map(|entry| *entry.type_info()), Some("application/json"));
+///     assert!(Formats::get("toml").is_none());
+/// }
+/// ```
+///
+/// Entries which share a key with the same priority are both iterated over, but looking up any
+/// key reports the duplicate:
+#[cfg_attr(feature = "macro", doc = "```")]
+#[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+/// use std::panic::catch_unwind;
+/// use type_registry::{register, Registry, RegistryExt};
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = ())]
+/// struct Services;
+///
+/// #[register(Services, key = "logger")]
+/// struct FileLogger;
+///
+/// #[register(Services, key = "logger")]
+/// struct ConsoleLogger;
+///
+/// fn main() {
+///     assert_eq!(Services::iter().count(), 2);
+///
+///     let error = catch_unwind(|| Services::get("logger").is_some()).unwrap_err();
+///     let message = error.downcast_ref::<String>().unwrap();
+///     assert!(message.starts_with("duplicate"));
+///     assert!(message.contains("ConsoleLogger") && message.contains("FileLogger"));
+/// }
+/// ```
+pub struct KeyIndex;
+
+impl<R: Registry + ?Sized> Index<R> for KeyIndex {
+    type Storage = HashMap<&'static str, RegistrationId<R>>;
+
+    fn allocate() -> Self::Storage {
+        HashMap::new()
+    }
+
+    fn associate(
+        storage: &mut Self::Storage,
+        id: RegistrationId<R>,
+        entry: RegistryEntry<R>
+    ) -> ControlFlow<()> {
+        let Some(key) = entry.key() else { return ControlFlow::Continue(()) };
+        if let Some(existing) = storage.insert(key, id) {
+            let registry = type_name::<R>();
+            let existing = existing.entry().raw().type_name();
+            let name = entry.raw().type_name();
+            panic!("duplicate '{registry}' key '{key}' for '{existing}' and '{name}'")
+        };
+        ControlFlow::Continue(())
+    }
+}
@@ -0,0 +1,58 @@
+use crate::logical::deprecation::Deprecation;
+use crate::logical::registration_id::RegistrationId;
+use crate::logical::registry::Registry;
+use crate::logical::registry_entry::RegistryEntry;
+
+/// The result of looking up an entry in a [registry](Registry) by key (see
+/// [RegistryExt::lookup](crate::RegistryExt::lookup)).
+pub struct KeyLookup<R: Registry + ?Sized> {
+    /// The ID of the entry which was found.
+    id: RegistrationId<R>,
+    /// The alias through which the entry was found, if it wasn't found by its primary key.
+    alias: Option<&'static str>
+}
+
+impl<R: Registry + ?Sized> KeyLookup<R> {
+    pub(crate) fn new(id: RegistrationId<R>, alias: Option<&'static str>) -> Self {
+        Self {
+            id,
+            alias
+        }
+    }
+
+    /// The ID of the entry which was found.
+    pub fn registration_id(&self) -> RegistrationId<R> {
+        self.id
+    }
+
+    /// The entry which was found.
+    pub fn entry(&self) -> RegistryEntry<R> {
+        self.id.entry()
+    }
+
+    /// The alias through which the entry was found, if it wasn't found by its primary key.
+    pub fn alias(&self) -> Option<&'static str> {
+        self.alias
+    }
+
+    /// The deprecation of the entry which was found, if it was registered as deprecated.
+    pub fn deprecation(&self) -> Option<&'static Deprecation> {
+        self.id.entry().deprecation()
+    }
+
+    /// Whether the entry was found through an alias rather than its primary key, e.g. so that
+    /// uses of old keys can be warned about.
+    pub fn is_alias(&self) -> bool {
+        self.alias.is_some()
+    }
+}
+
+// Have to manually derive Copy/Clone, as the derive macro requires a Sized bound.
+
+impl<R: Registry + ?Sized> Clone for KeyLookup<R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R: Registry + ?Sized> Copy for KeyLookup<R> {}
@@ -0,0 +1,108 @@
+use std::any::type_name;
+use std::collections::BTreeMap;
+use crate::logical::registration_id::RegistrationId;
+use crate::logical::registry::Registry;
+
+/// A node in the tree of the keys in a [registry](Registry) (see
+/// [KeyTreeIndex](crate::KeyTreeIndex)). Keys are split into segments on `.`, so e.g. the key
+/// `codec.image.png` is at the path `codec` → `image` → `png` from the root. Nodes are only
+/// associated with a registration if their full key was registered, so intermediate nodes such as
+/// `codec.image` may have children but no registration.
+pub struct KeyTree<R: Registry + ?Sized> {
+    /// The registration with this node's key, if any.
+    id: Option<RegistrationId<R>>,
+    /// The child nodes, by their final key segment.
+    children: BTreeMap<&'static str, KeyTree<R>>
+}
+
+impl<R: Registry + ?Sized> KeyTree<R> {
+    /// The separator between the segments of a key.
+    pub const SEPARATOR: char = '.';
+
+    /// The wildcard which matches any single segment in [matching](KeyTree::matching) patterns.
+    pub const WILDCARD: &'static str = "*";
+
+    pub(crate) fn new() -> Self {
+        Self {
+            id: None,
+            children: BTreeMap::new()
+        }
+    }
+
+    pub(crate) fn insert(&mut self, key: &'static str, id: RegistrationId<R>) {
+        let node = key.split(Self::SEPARATOR).fold(
+            self,
+            |node, segment| node.children.entry(segment).or_insert_with(Self::new)
+        );
+
+        if let Some(existing) = node.id.replace(id) {
+            let registry = type_name::<R>();
+            let existing = existing.entry().raw().type_name();
+            let name = id.entry().raw().type_name();
+            panic!("duplicate '{registry}' key '{key}' for '{existing}' and '{name}'")
+        }
+    }
+
+    /// The registration with this node's key, if any.
+    pub fn id(&self) -> Option<RegistrationId<R>> {
+        self.id
+    }
+
+    /// Gets the node at the given (dotted) path relative to this node. The empty path is this
+    /// node itself.
+    pub fn get(&self, path: &str) -> Option<&Self> {
+        if path.is_empty() {
+            return Some(self)
+        }
+
+        path.split(Self::SEPARATOR).try_fold(
+            self,
+            |node, segment| node.children.get(segment)
+        )
+    }
+
+    /// The immediate children of this node, in order of their final key segment.
+    pub fn children(&self) -> impl Iterator<Item=(&'static str, &Self)> {
+        self.children.iter().map(|(segment, child)| (*segment, child))
+    }
+
+    /// The registrations of this node and all of its descendants, depth-first and in key order.
+    pub fn subtree(&self) -> Vec<RegistrationId<R>> {
+        let mut ids = Vec::new();
+        self.collect_subtree(&mut ids);
+        ids
+    }
+
+    fn collect_subtree(&self, ids: &mut Vec<RegistrationId<R>>) {
+        ids.extend(self.id);
+        for child in self.children.values() {
+            child.collect_subtree(ids);
+        }
+    }
+
+    /// The registrations whose keys, relative to this node, match the given pattern, in key
+    /// order. Each segment of the pattern must match a key segment exactly, except for
+    /// [wildcard](KeyTree::WILDCARD) segments which match any single segment, e.g. `codec.*.png`
+    /// matches `codec.image.png` but not `codec.png` or `codec.image.lossless.png`.
+    pub fn matching(&self, pattern: &str) -> Vec<RegistrationId<R>> {
+        let segments: Vec<&str> = pattern.split(Self::SEPARATOR).collect();
+        let mut ids = Vec::new();
+        self.collect_matching(&segments, &mut ids);
+        ids
+    }
+
+    fn collect_matching(&self, segments: &[&str], ids: &mut Vec<RegistrationId<R>>) {
+        let Some((segment, rest)) = segments.split_first() else {
+            ids.extend(self.id);
+            return
+        };
+
+        if *segment == Self::WILDCARD {
+            for child in self.children.values() {
+                child.collect_matching(rest, ids);
+            }
+        } else if let Some(child) = self.children.get(segment) {
+            child.collect_matching(rest, ids);
+        }
+    }
+}
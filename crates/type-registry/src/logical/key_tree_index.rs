@@ -0,0 +1,59 @@
+use std::ops::ControlFlow;
+use crate::logical::index::Index;
+use crate::logical::key_tree::KeyTree;
+use crate::logical::registration_id::RegistrationId;
+use crate::logical::registry::Registry;
+use crate::logical::registry_entry::RegistryEntry;
+
+/// [Index] of the entries in a [registry](Registry) by their keys, treating the keys as dotted
+/// paths in a [tree](KeyTree). Entries without keys or which were overridden (see
+/// [KeyIndex](crate::KeyIndex)) are not indexed.
+///
+/// E.g.:
+#[cfg_attr(feature = "macro", doc = "```")]
+#[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+/// use type_registry::{register, Registry, RegistryExt};
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = ())]
+/// struct Codecs;
+///
+/// #[register(Codecs, key = "codec.image.png")]
+/// struct Png;
+///
+/// #[register(Codecs, key = "codec.image.jpeg")]
+/// struct Jpeg;
+///
+/// #[register(Codecs, key = "codec.audio.flac")]
+/// struct Flac;
+///
+/// fn main() {
+///     let keys = |entries: Vec<_>| entries.into_iter()
+///         .map(|entry: type_registry::RegistryEntry<Codecs>| entry.key().unwrap())
+///         .collect::<Vec<_>>();
+///
+///     assert_eq!(keys(Codecs::children("codec.image")), ["codec.image.jpeg", "codec.image.png"]);
+///     assert_eq!(keys(Codecs::subtree("codec")).len(), 3);
+///     assert_eq!(keys(Codecs::matching("codec.*.png")), ["codec.image.png"]);
+/// }
+/// ```
+pub struct KeyTreeIndex;
+
+impl<R: Registry + ?Sized> Index<R> for KeyTreeIndex {
+    type Storage = KeyTree<R>;
+
+    fn allocate() -> Self::Storage {
+        KeyTree::new()
+    }
+
+    fn associate(
+        storage: &mut Self::Storage,
+        id: RegistrationId<R>,
+        entry: RegistryEntry<R>
+    ) -> ControlFlow<()> {
+        if let Some(key) = entry.key() {
+            storage.insert(key, id);
+        }
+        ControlFlow::Continue(())
+    }
+}
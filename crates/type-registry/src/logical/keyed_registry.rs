@@ -0,0 +1,55 @@
+use crate::logical::key_lookup::KeyLookup;
+use crate::logical::registry::Registry;
+use crate::logical::registry_entry::RegistryEntry;
+use crate::logical::registry_ext::RegistryExt;
+
+/// A [registry](Registry) whose entries are looked up by a particular type of key, rather than by
+/// any string (see [RegistryExt::get]). Keys are compared to the keys entries were registered
+/// under as strings. Can be implemented by the Registry derive with `#[registry(key = ...)]`.
+///
+/// E.g.:
+#[cfg_attr(feature = "macro", doc = "```")]
+#[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+/// use type_registry::{register, KeyedRegistry, Registry};
+///
+/// enum FormatKey {
+///     Json,
+///     Yaml
+/// }
+///
+/// impl AsRef<str> for FormatKey {
+///     fn as_ref(&self) -> &str {
+///         match self {
+///             FormatKey::Json => "json",
+///             FormatKey::Yaml => "yaml"
+///         }
+///     }
+/// }
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = (), key = FormatKey)]
+/// struct Formats;
+///
+/// #[register(Formats, key = "json")]
+/// struct Json;
+///
+/// fn main() {
+///     assert!(Formats::get_by_key(&FormatKey::Json).is_some());
+///     assert!(Formats::get_by_key(&FormatKey::Yaml).is_none());
+/// }
+/// ```
+pub trait KeyedRegistry: Registry {
+    /// The type of the keys which entries are looked up by.
+    type Key: AsRef<str> + ?Sized;
+
+    /// Gets the entry registered under the given key or alias, if any (see [RegistryExt::get]).
+    fn get_by_key(key: &Self::Key) -> Option<RegistryEntry<Self>> {
+        Self::get(key.as_ref())
+    }
+
+    /// Looks up the entry registered under the given key or alias, if any (see
+    /// [RegistryExt::lookup]).
+    fn lookup_by_key(key: &Self::Key) -> Option<KeyLookup<Self>> {
+        Self::lookup(key.as_ref())
+    }
+}
@@ -1,20 +1,89 @@
 //! Represents logical type-registries built on top of the [raw registry](crate::raw::REGISTRY).
 
+mod alias_index;
+pub use alias_index::AliasIndex;
+
+mod compatible;
+pub use compatible::Compatible;
+
+mod const_default;
+pub use const_default::ConstDefault;
+
+mod dependency_order;
+
+mod deprecation;
+pub use deprecation::Deprecation;
+
+mod explicit_id_index;
+pub use explicit_id_index::ExplicitIdIndex;
+
+mod fn_info;
+pub use fn_info::FnInfo;
+
+mod incompatibility;
+pub use incompatibility::Incompatibility;
+
 mod index;
 pub use index::Index;
 
 mod iter;
 pub use iter::Iter;
 
+mod iter_all;
+pub use iter_all::IterAll;
+
+mod iter_ordered;
+pub use iter_ordered::IterOrdered;
+
+mod key_index;
+pub use key_index::KeyIndex;
+
+mod key_tree;
+pub use key_tree::KeyTree;
+
+mod key_tree_index;
+pub use key_tree_index::KeyTreeIndex;
+
+mod key_lookup;
+pub use key_lookup::KeyLookup;
+
+mod keyed_registry;
+pub use keyed_registry::KeyedRegistry;
+
+mod name_index;
+pub use name_index::NameIndex;
+
+mod name_index_storage;
+pub use name_index_storage::NameIndexStorage;
+
+mod register_types;
+
+mod parse_version_error;
+pub use parse_version_error::ParseVersionError;
+
 mod registered;
 pub use registered::Registered;
 
+mod registered_fn;
+pub use registered_fn::RegisteredFn;
+
+mod registered_value;
+pub use registered_value::RegisteredValue;
+
+mod registered_variant;
+pub use registered_variant::RegisteredVariant;
+
+mod registered_variants;
+pub use registered_variants::RegisteredVariants;
+
 mod registration;
 pub use registration::Registration;
 
 mod registration_id;
 pub use registration_id::RegistrationId;
 
+mod registration_order;
+
 mod registry;
 pub use registry::Registry;
 
@@ -24,5 +93,34 @@ pub use registry_entry::RegistryEntry;
 mod registry_ext;
 pub use registry_ext::RegistryExt;
 
+mod stable_name_index;
+pub use stable_name_index::StableNameIndex;
+
+mod suggest;
+
+mod tag_index;
+pub use tag_index::TagIndex;
+
 mod type_info;
 pub use type_info::TypeInfo;
+
+mod type_info_for;
+pub use type_info_for::TypeInfoFor;
+
+mod value_info;
+pub use value_info::ValueInfo;
+
+mod value_registry;
+pub use value_registry::ValueRegistry;
+
+mod values;
+pub use values::Values;
+
+mod variant_info;
+pub use variant_info::VariantInfo;
+
+mod version;
+pub use version::Version;
+
+mod version_req;
+pub use version_req::VersionReq;
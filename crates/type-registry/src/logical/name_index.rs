@@ -0,0 +1,51 @@
+use std::ops::ControlFlow;
+use crate::logical::index::Index;
+use crate::logical::name_index_storage::NameIndexStorage;
+use crate::logical::registration_id::RegistrationId;
+use crate::logical::registry::Registry;
+use crate::logical::registry_entry::RegistryEntry;
+
+/// [Index] of the entries in a [registry](Registry) by the [names](std::any::type_name) of the
+/// registered types, both in full and without their module paths (see [NameIndexStorage]).
+///
+/// E.g.:
+#[cfg_attr(feature = "macro", doc = "```")]
+#[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+/// use type_registry::{register, Registry, RegistryExt};
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = ())]
+/// struct Analysers;
+///
+/// mod analysers {
+///     use type_registry::register;
+///
+///     #[register(super::Analysers)]
+///     pub struct LenAnalyser;
+/// }
+///
+/// fn main() {
+///     let full_name = std::any::type_name::<analysers::LenAnalyser>();
+///     assert!(Analysers::find_by_name(full_name).is_some());
+///     assert!(Analysers::find_by_name("LenAnalyser").is_some());
+///     assert!(Analysers::find_by_name("NumFinderAnalyser").is_none());
+/// }
+/// ```
+pub struct NameIndex;
+
+impl<R: Registry + ?Sized> Index<R> for NameIndex {
+    type Storage = NameIndexStorage<R>;
+
+    fn allocate() -> Self::Storage {
+        NameIndexStorage::new()
+    }
+
+    fn associate(
+        storage: &mut Self::Storage,
+        id: RegistrationId<R>,
+        entry: RegistryEntry<R>
+    ) -> ControlFlow<()> {
+        storage.insert(entry.raw().type_name(), id);
+        ControlFlow::Continue(())
+    }
+}
@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+use crate::logical::registration_id::RegistrationId;
+use crate::logical::registry::Registry;
+
+/// The storage of the [name index](crate::NameIndex) of a [registry](Registry).
+///
+/// Maps both full names (e.g. `my_crate::analysers::LenAnalyser`) and short names, which omit the
+/// module path (e.g. `LenAnalyser`). Names shared by more than one registration are ambiguous,
+/// and are not found.
+pub struct NameIndexStorage<R: Registry + ?Sized> {
+    /// Registrations by full name, or [None] if ambiguous.
+    full_names: HashMap<&'static str, Option<RegistrationId<R>>>,
+    /// Registrations by short name, or [None] if ambiguous.
+    short_names: HashMap<&'static str, Option<RegistrationId<R>>>
+}
+
+impl<R: Registry + ?Sized> NameIndexStorage<R> {
+    pub(crate) fn new() -> Self {
+        Self {
+            full_names: HashMap::new(),
+            short_names: HashMap::new()
+        }
+    }
+
+    pub(crate) fn insert(&mut self, name: &'static str, id: RegistrationId<R>) {
+        Self::insert_unless_ambiguous(&mut self.full_names, name, id);
+        Self::insert_unless_ambiguous(&mut self.short_names, Self::short_name(name), id);
+    }
+
+    fn insert_unless_ambiguous(
+        names: &mut HashMap<&'static str, Option<RegistrationId<R>>>,
+        name: &'static str,
+        id: RegistrationId<R>
+    ) {
+        names.entry(name)
+            .and_modify(|existing| *existing = None)
+            .or_insert(Some(id));
+    }
+
+    /// Removes the module path from a type name. Generic arguments are kept intact, e.g.
+    /// `my_crate::Codec<alloc::string::String>` becomes `Codec<alloc::string::String>`.
+    pub(crate) fn short_name(name: &'static str) -> &'static str {
+        let path_end = name.find('<').unwrap_or(name.len());
+        match name[..path_end].rfind("::") {
+            Some(separator) => &name[separator + 2..],
+            None => name
+        }
+    }
+
+    /// Gets the registration with the given full or short name, unless the name is ambiguous.
+    pub fn get(&self, name: &str) -> Option<RegistrationId<R>> {
+        match self.full_names.get(name) {
+            Some(id) => *id,
+            None => self.short_names.get(name).copied().flatten()
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The error when a [version](crate::Version) or [version requirement](crate::VersionReq) is
+/// invalid.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseVersionError {
+    /// The invalid input.
+    input: String
+}
+
+impl ParseVersionError {
+    pub(crate) fn new(input: &str) -> Self {
+        Self {
+            input: input.to_owned()
+        }
+    }
+
+    /// The invalid input.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl Display for ParseVersionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("invalid version or version requirement '{}'", self.input))
+    }
+}
+
+impl Error for ParseVersionError {}
@@ -0,0 +1,77 @@
+/// Registers types with a [registry](crate::Registry) without needing the
+/// [register](crate::register) macro (and therefore the `macro` feature), or the ability to
+/// annotate the types themselves. As such it can be used to register types from other crates.
+///
+/// Each type's [type-info](crate::Registry::TypeInfo) is initialised from its
+/// [TypeInfoFor](crate::TypeInfoFor) implementation, unless an initialisation expression follows
+/// the type.
+///
+/// Options are given as the modifiers of the [raw entry](crate::raw::RegistryEntry) in brackets
+/// after the type, as with [registration](crate::registration), e.g. `String [with_key("string")]`.
+/// Unlike with the [register](crate::register) macro, no
+/// [stable name](crate::RegistryEntry::stable_name) is recorded unless given with
+/// `with_stable_name`, as the module the types are registered from needn't be the one they're
+/// defined in.
+///
+/// For example:
+/// ```
+/// use type_registry::{register_types, Registry, RegistryExt};
+///
+/// struct SizeRegistry;
+///
+/// impl Registry for SizeRegistry {
+///     type TypeInfo = usize;
+///
+///     fn name() -> &'static str {
+///         "Sizes"
+///     }
+/// }
+///
+/// register_types!(
+///     SizeRegistry:
+///     String [with_key("string"), with_stable_name("alloc::string::String")] = 24,
+///     Vec<u8> = 24,
+///     std::num::NonZeroU8 = 1
+/// );
+///
+/// fn main() {
+///     assert_eq!(SizeRegistry::iter().count(), 3);
+///     assert_eq!(SizeRegistry::get("string").map(|entry| *entry.type_info()), Some(24));
+///     assert!(SizeRegistry::find_by_stable_name("alloc::string::String").is_some());
+/// }
+/// ```
+#[macro_export]
+macro_rules! register_types {
+    (
+        $registry_type:ty :
+        $(
+            $registered_type:ty
+            $([$($modifier:ident($($argument:expr),* $(,)?)),+ $(,)?])?
+            $(= $init_type_info_expr:expr)?
+        ),+ $(,)?
+    ) => {
+        $(
+            // SAFETY: registration is created by the registration! macro for the same types.
+            unsafe impl $crate::Registered<$registry_type> for $registered_type {
+                fn register() -> $crate::Registration<$registry_type, Self> {
+                    $crate::registration!(
+                        $registry_type, $registered_type $(; $($modifier($($argument),*)),+)?
+                    )
+                }
+
+                fn type_info() -> &'static <$registry_type as $crate::Registry>::TypeInfo {
+                    static TYPE_INFO: <$registry_type as $crate::Registry>::TypeInfo = $crate::register_types!(
+                        @init_type_info_expr $registry_type, $registered_type $(, $init_type_info_expr)?
+                    );
+                    &TYPE_INFO
+                }
+            }
+        )+
+    };
+    (@init_type_info_expr $registry_type:ty, $registered_type:ty) => {
+        <<$registry_type as $crate::Registry>::TypeInfo as $crate::TypeInfoFor<$registered_type>>::TYPE_INFO
+    };
+    (@init_type_info_expr $registry_type:ty, $registered_type:ty, $init_type_info_expr:expr) => {
+        $init_type_info_expr
+    };
+}
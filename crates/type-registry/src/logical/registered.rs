@@ -3,6 +3,11 @@ use crate::logical::registration::Registration;
 use crate::logical::registry::Registry;
 
 /// Trait which marks a type as registered with a particular [registry](Registry).
+///
+/// # Safety
+///
+/// [register](Registered::register) must return a [Registration] created by the
+/// [registration! macro](crate::registration) for the same registry and type.
 pub unsafe trait Registered<R: Registry + ?Sized>: Any {
     /// Register the type with the [registry](Registry). Should internally use the
     /// [registration! macro (click for example)](crate::registration).
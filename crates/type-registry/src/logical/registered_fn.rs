@@ -0,0 +1,13 @@
+/// Trait implemented by the marker type which the [register](crate::register) macro generates
+/// when registering a function. Gives access to the function from the
+/// [type-info's](crate::Registry::TypeInfo) `new::<T>()` constructor (see [FnInfo](crate::FnInfo)).
+pub trait RegisteredFn: 'static {
+    /// The type of function pointer to the registered function.
+    type Fn: Copy + 'static;
+
+    /// Pointer to the registered function.
+    const FN: Self::Fn;
+
+    /// The name of the registered function.
+    const NAME: &'static str;
+}
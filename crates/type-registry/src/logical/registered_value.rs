@@ -0,0 +1,13 @@
+/// Trait implemented by the marker type which the [register](crate::register) macro generates
+/// when registering a static or const. Gives access to the value from the
+/// [type-info](crate::Registry::TypeInfo) (see [ValueInfo](crate::ValueInfo)).
+pub trait RegisteredValue: 'static {
+    /// The type of the registered value.
+    type Value: ?Sized + Sync + 'static;
+
+    /// The name of the registered static or const.
+    const NAME: &'static str;
+
+    /// Gets the registered value.
+    fn value() -> &'static Self::Value;
+}
@@ -0,0 +1,17 @@
+/// Trait implemented by the marker type which the
+/// [register_variants](crate::register_variants) macro generates for each variant of an enum.
+/// Gives access to information about the variant from the variant's
+/// [type-info](crate::Registry::TypeInfo) (see [VariantInfo](crate::VariantInfo)).
+pub trait RegisteredVariant: 'static {
+    /// The enum to which the variant belongs.
+    type Enum: 'static;
+
+    /// The name of the variant.
+    const NAME: &'static str;
+
+    /// The variant's discriminant, whether explicit or implicit.
+    const DISCRIMINANT: i128;
+
+    /// The number of fields of the variant.
+    const FIELD_COUNT: usize;
+}
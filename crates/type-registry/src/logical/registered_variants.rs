@@ -0,0 +1,9 @@
+use crate::logical::registration_id::RegistrationId;
+use crate::logical::registry::Registry;
+
+/// Trait implemented by an enum whose variants have been registered with a particular
+/// [registry](Registry) by the [register_variants](crate::register_variants) macro.
+pub trait RegisteredVariants<R: Registry + ?Sized> {
+    /// Gets the [ID](RegistrationId) of the registration of this value's variant.
+    fn registration_id(&self) -> RegistrationId<R>;
+}
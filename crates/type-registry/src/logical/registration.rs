@@ -31,7 +31,10 @@ impl<
     }
 }
 
-/// Should be used to implement [Registered::register].
+/// Should be used to implement [Registered::register]. Additional information about the
+/// registration (e.g. its key) can be given by following the types with calls to the const
+/// `with_*` methods of the [raw entry](RegistryEntry), e.g.
+/// `registration!(MyRegistry, MyType; with_key("my-type"))`.
 /// 
 /// For example:
 /// ```
@@ -63,7 +66,7 @@ impl<
 /// ```
 #[macro_export]
 macro_rules! registration {
-    ($registry_type:ty, $registered_type:ty) => {
+    ($registry_type:ty, $registered_type:ty $(; $($modifier:ident($($argument:expr),* $(,)?)),+ $(,)?)?) => {
         {
             use $crate::reexports::linkme::distributed_slice;
             use $crate::raw::{RegistryEntry, REGISTRY};
@@ -71,9 +74,11 @@ macro_rules! registration {
 
             #[distributed_slice(REGISTRY)]
             #[linkme(crate=$crate::reexports::linkme)]
-            static REGISTRATION: RegistryEntry = RegistryEntry::new::<$registry_type, $registered_type>();
+            static REGISTRATION: RegistryEntry = RegistryEntry::new::<$registry_type, $registered_type>()
+                $($(.$modifier($($argument),*))+)?;
 
-            // SAFETY: Created with same types immediately above.
+            // SAFETY: Created with same types immediately above (the modifiers can't change the
+            // types of the entry).
             unsafe { Registration::<$registry_type, $registered_type>::new(&REGISTRATION) }
         }
     };
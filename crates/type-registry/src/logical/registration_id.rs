@@ -3,8 +3,9 @@ use std::borrow::Borrow;
 use std::fmt::{Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
-use crate::logical::{Registered, RegistryEntry, RegistryExt};
+use crate::logical::{ExplicitIdIndex, Registered, RegisteredVariants, RegistryEntry, RegistryExt};
 use crate::logical::registry::Registry;
+use crate::logical::registration_order::RegistrationOrder;
 use crate::raw::{RegistrationId as RawRegistrationId, RegistryEntry as RawRegistryEntry};
 
 /// Identifies a [registered](Registered) type in a particular [registry](Registry).
@@ -29,7 +30,6 @@ impl<R: Registry + ?Sized> RegistrationId<R> {
         }
     }
 
-    #[allow(dead_code)]
     pub(crate) fn raw(self) -> RawRegistrationId {
         self.raw_id
     }
@@ -39,6 +39,12 @@ impl<R: Registry + ?Sized> RegistrationId<R> {
         self.registry_index
     }
 
+    /// The explicit numeric ID the type was registered with, if any. Unlike the
+    /// [index](RegistrationId::index), this doesn't change when other types are registered.
+    pub fn explicit_id(self) -> Option<u32> {
+        self.entry().explicit_id()
+    }
+
     /// Gets the 
     pub fn entry(self) -> RegistryEntry<R> {
         // SAFETY: raw_id is for R
@@ -49,6 +55,16 @@ impl<R: Registry + ?Sized> RegistrationId<R> {
         self.entry().type_info()
     }
 
+    /// Gets the ID of the entry which overrode this one, if another entry with the same key took
+    /// precedence over it (see [IterAll](crate::IterAll)).
+    pub fn overridden_by(self) -> Option<Self> {
+        let order = RegistrationOrder::of::<R>();
+        let overrider = order.overrider(self.registry_index)?;
+        Some(Self::new(order.entries()[overrider], overrider))
+    }
+
+    /// Gets the ID of the registration of a type (even if its entry was overridden, see
+    /// [RegistrationId::overridden_by]).
     pub fn of<T: Registered<R> + ?Sized>() -> Self {
         // SAFETY: T is registered to R
         unsafe {
@@ -56,6 +72,18 @@ impl<R: Registry + ?Sized> RegistrationId<R> {
         }
     }
 
+    /// Gets the ID of the registration with the given explicit numeric ID, if any (see
+    /// [ExplicitIdIndex]).
+    pub fn from_explicit(explicit_id: u32) -> Option<Self> {
+        R::index::<ExplicitIdIndex>().get(&explicit_id).copied()
+    }
+
+    /// Gets the ID of the registration of a value's variant, for enums registered with
+    /// [register_variants](crate::register_variants).
+    pub fn of_variant<E: RegisteredVariants<R> + ?Sized>(value: &E) -> Self {
+        value.registration_id()
+    }
+
     /// SAFETY: raw_entry must be for R
     pub(crate) unsafe fn from_raw_entry_unchecked(raw_entry: &RawRegistryEntry) -> Self {
         Self::from_type_id_unchecked(raw_entry.type_id())
@@ -71,7 +99,7 @@ impl<R: Registry + ?Sized> RegistrationId<R> {
 
 impl<R: Registry + ?Sized> Clone for RegistrationId<R> {
     fn clone(&self) -> Self {
-        Self::new(self.raw_id.index(), self.registry_index)
+        *self
     }
 }
 
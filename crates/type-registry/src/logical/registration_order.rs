@@ -0,0 +1,140 @@
+use std::any::{type_name, Any};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use generic_static::StaticTypeMap;
+use crate::logical::registry::Registry;
+use crate::raw::{REGISTRY, RegistryId};
+
+/// The order of the entries of a [registry](Registry), in terms of their indices in the
+/// [raw registry](REGISTRY). The order of the raw registry is decided by the linker and so may
+/// differ between builds, so entries are instead sorted by stable name (or, for entries without
+/// one, by type name), then by key. Unlike [type names](std::any::type_name), stable names don't
+/// depend on the compiler version.
+///
+/// Of the entries sharing a key, only the one with the highest priority (non-weak entries taking
+/// precedence over weak ones) is active, and the others are overridden by it. Every entry has its
+/// own registry index: the active entries come first, followed by the overridden ones.
+pub(crate) struct RegistrationOrder {
+    /// The raw indices of all entries, by registry index.
+    entries: Vec<usize>,
+    /// The number of active entries, which precede the overridden entries.
+    active_count: usize,
+    /// The registry index of the entry which overrode each entry, if any, by registry index.
+    overriders: Vec<Option<usize>>
+}
+
+impl RegistrationOrder {
+    /// Gets the order of a [registry](Registry). Computed once per registry, at which point the
+    /// registry's explicit IDs are checked to be unique.
+    pub fn of<R: Registry + ?Sized>() -> &'static Self {
+        static ORDER_TYPE_MAP: OnceLock<StaticTypeMap<Box<dyn Any + Send + Sync>>> = OnceLock::new();
+        let order_type_map = ORDER_TYPE_MAP.get_or_init(StaticTypeMap::new);
+
+        let any = order_type_map.call_once::<fn(R), _>(|| Box::new(Self::new::<R>()));
+
+        any.downcast_ref().expect("order is associated to registry")
+    }
+
+    fn new<R: Registry + ?Sized>() -> Self {
+        let mut order: Vec<usize> = REGISTRY.iter()
+            .enumerate()
+            .filter(|(_, entry)| entry.registry_id() == RegistryId::of::<R>())
+            .map(|(raw_index, _)| raw_index)
+            .collect();
+
+        order.sort_by_key(|raw_index| Self::sort_key(*raw_index));
+
+        let overriders = Self::overriders(&order);
+
+        let (mut entries, overridden): (Vec<usize>, Vec<usize>) = order.into_iter()
+            .partition(|raw_index| !overriders.contains_key(raw_index));
+        let active_count = entries.len();
+        entries.extend(overridden);
+
+        Self::check_explicit_ids::<R>(&entries[..active_count]);
+
+        let registry_indices: HashMap<usize, usize> = entries.iter()
+            .enumerate()
+            .map(|(registry_index, raw_index)| (*raw_index, registry_index))
+            .collect();
+
+        let overriders = entries.iter()
+            .map(|raw_index| overriders.get(raw_index).map(|overrider| registry_indices[overrider]))
+            .collect();
+
+        Self {
+            entries,
+            active_count,
+            overriders
+        }
+    }
+
+    /// The raw indices of the active entries, by registry index.
+    pub fn active(&self) -> &[usize] {
+        &self.entries[..self.active_count]
+    }
+
+    /// The raw indices of all entries, by registry index.
+    pub fn entries(&self) -> &[usize] {
+        &self.entries
+    }
+
+    /// The registry index of the entry which overrode the entry with the given registry index, if
+    /// it was overridden.
+    pub fn overrider(&self, registry_index: usize) -> Option<usize> {
+        self.overriders[registry_index]
+    }
+
+    /// The key by which entries are sorted: the stable name (or type name), then the key.
+    pub(crate) fn sort_key(raw_index: usize) -> (&'static str, Option<&'static str>) {
+        let entry = &REGISTRY[raw_index];
+        (entry.stable_name().unwrap_or(entry.type_name()), entry.key())
+    }
+
+    /// Maps the raw index of each overridden entry to the raw index of the entry which overrode
+    /// it. If the highest-priority entries for a key have the same priority, none of them are
+    /// overridden, leaving the [key index](crate::KeyIndex) to report the duplicate key.
+    pub(crate) fn overriders(order: &[usize]) -> HashMap<usize, usize> {
+        let rank = |raw_index: usize| {
+            let entry = &REGISTRY[raw_index];
+            (!entry.is_weak(), entry.priority())
+        };
+
+        let mut by_key: HashMap<&'static str, Vec<usize>> = HashMap::new();
+        for raw_index in order {
+            if let Some(key) = REGISTRY[*raw_index].key() {
+                by_key.entry(key).or_default().push(*raw_index);
+            }
+        }
+
+        let mut overriders = HashMap::new();
+        for raw_indices in by_key.into_values() {
+            let highest = raw_indices.iter().map(|raw_index| rank(*raw_index)).max().expect("key has entries");
+            let overrider = *raw_indices.iter()
+                .find(|raw_index| rank(**raw_index) == highest)
+                .expect("key has highest entry");
+
+            for raw_index in raw_indices {
+                if rank(raw_index) != highest {
+                    overriders.insert(raw_index, overrider);
+                }
+            }
+        }
+
+        overriders
+    }
+
+    /// Panics if two of the given entries of a [registry](Registry) share an explicit ID.
+    fn check_explicit_ids<R: Registry + ?Sized>(order: &[usize]) {
+        let mut seen = HashMap::new();
+        for raw_index in order {
+            let entry = &REGISTRY[*raw_index];
+            let Some(explicit_id) = entry.explicit_id() else { continue };
+            if let Some(existing) = seen.insert(explicit_id, entry.type_name()) {
+                let registry = type_name::<R>();
+                let name = entry.type_name();
+                panic!("duplicate '{registry}' explicit ID {explicit_id} for '{existing}' and '{name}'")
+            }
+        }
+    }
+}
@@ -1,4 +1,6 @@
 use crate::TypeInfo;
+use crate::logical::deprecation::Deprecation;
+use crate::logical::registry_entry::RegistryEntry;
 
 /// A logical registry of types.
 pub trait Registry: 'static {
@@ -7,4 +9,12 @@ pub trait Registry: 'static {
 
     /// A name for the registry. Only used for informational purposes.
     fn name() -> &'static str;
+
+    /// Called whenever a deprecated entry is looked up by key or name (e.g. with
+    /// [RegistryExt::get](crate::RegistryExt::get) or
+    /// [RegistryExt::find_by_name](crate::RegistryExt::find_by_name)), e.g. to log a warning.
+    /// Does nothing by default.
+    fn on_deprecated_use(entry: RegistryEntry<Self>, deprecation: &'static Deprecation) {
+        let _ = (entry, deprecation);
+    }
 }
@@ -4,6 +4,7 @@ use crate::logical::registry::Registry;
 use crate::raw::{RegistryEntry as RawRegistryEntry};
 use crate::raw::RegistryId;
 use crate::{RegistrationId};
+use crate::logical::deprecation::Deprecation;
 
 /// An entry describing a type registered to a [registry](Registry).
 #[derive(Copy, Clone)]
@@ -52,6 +53,74 @@ impl<R: Registry + ?Sized> RegistryEntry<R> {
         }
     }
 
+    /// Gets the [name](std::any::type_name) of the registered type.
+    pub fn type_name(&self) -> &'static str {
+        self.raw_entry.type_name()
+    }
+
+    /// Gets the key this entry was registered under, if any.
+    pub fn key(&self) -> Option<&'static str> {
+        self.raw_entry.key()
+    }
+
+    /// Gets the alternative keys this entry was registered under (see
+    /// [AliasIndex](crate::AliasIndex)).
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.raw_entry.aliases()
+    }
+
+    /// Gets the stable name this entry was registered with, if any. Unlike the
+    /// [type name](RegistryEntry::type_name), this is only derived from the source (see
+    /// [StableNameIndex](crate::StableNameIndex)), so is suitable for persisting.
+    pub fn stable_name(&self) -> Option<&'static str> {
+        self.raw_entry.stable_name()
+    }
+
+    /// Gets the tags this entry was registered with (see [TagIndex](crate::TagIndex)).
+    pub fn tags(&self) -> &'static [&'static str] {
+        self.raw_entry.tags()
+    }
+
+    /// Gets the deprecation of this entry, if it was registered as deprecated.
+    pub fn deprecation(&self) -> Option<&'static Deprecation> {
+        self.raw_entry.deprecation()
+    }
+
+    /// Gets the version this entry was registered with, if any.
+    pub fn version(&self) -> Option<&'static str> {
+        self.raw_entry.version()
+    }
+
+    /// Gets the requirement on the host version this entry was registered with, if any (see
+    /// [RegistryExt::compatible_with](crate::RegistryExt::compatible_with)).
+    pub fn requires(&self) -> Option<&'static str> {
+        self.raw_entry.requires()
+    }
+
+    /// Gets the explicit numeric ID this entry was registered with, if any (see
+    /// [RegistrationId::from_explicit]).
+    pub fn explicit_id(&self) -> Option<u32> {
+        self.raw_entry.explicit_id()
+    }
+
+    /// Gets the priority of this entry over others with the same key (see
+    /// [RegistryExt::iter_all](crate::RegistryExt::iter_all)).
+    pub fn priority(&self) -> i32 {
+        self.raw_entry.priority()
+    }
+
+    /// Gets whether this entry is a fallback for others with the same key, which any non-weak
+    /// entry overrides.
+    pub fn is_weak(&self) -> bool {
+        self.raw_entry.is_weak()
+    }
+
+    /// Gets the [ID](RegistrationId) of the entry which overrode this one, if any (see
+    /// [RegistrationId::overridden_by]).
+    pub fn overridden_by(&self) -> Option<RegistrationId<R>> {
+        self.registration_id().overridden_by()
+    }
+
     /// Gets the [type-info](Registry::TypeInfo) that was provided for this entry.
     pub fn type_info(&self) -> &'static R::TypeInfo {
         let any = self.raw_entry.type_info().as_any() as &dyn Any;
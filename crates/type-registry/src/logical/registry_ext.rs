@@ -3,16 +3,109 @@ use std::ops::ControlFlow;
 use std::sync::OnceLock;
 use generic_static::StaticTypeMap;
 use crate::logical::index::Index;
-use crate::logical::Iter;
+use crate::logical::{AliasIndex, Compatible, Iter, IterAll, IterOrdered, KeyIndex, KeyLookup, KeyTreeIndex, NameIndex, RegistrationId, RegistryEntry, StableNameIndex, TagIndex, Version};
 use crate::logical::registry::Registry;
+use crate::logical::name_index_storage::NameIndexStorage;
+use crate::logical::suggest::suggest;
 
 /// Extension methods for [registries](Registry).
 pub trait RegistryExt: Registry {
     /// Iterates over the entries in a [registry](Registry).
     fn iter() -> Iter<Self>;
 
+    /// Iterates over all the entries in a [registry](Registry), including those which were
+    /// overridden by another entry with the same key (see [IterAll]).
+    fn iter_all() -> IterAll<Self>;
+
+    /// Iterates over the entries in a [registry](Registry), respecting their `before`/`after`
+    /// ordering constraints (see [IterOrdered]).
+    fn iter_ordered() -> IterOrdered<Self>;
+
     /// Accesses an [index](Index) associated with a [registry](Registry).
     fn index<I: Index<Self>>() -> &'static I::Storage;
+
+    /// Gets the entry registered under the given key or alias, if any (see [KeyIndex] and
+    /// [AliasIndex]).
+    fn get(key: &str) -> Option<RegistryEntry<Self>>;
+
+    /// Looks up the entry registered under the given key or alias, if any, reporting whether it
+    /// was found through an alias (see [AliasIndex]).
+    fn lookup(key: &str) -> Option<KeyLookup<Self>>;
+
+    /// Gets the entry for the type with the given name, which may be the full name or, if
+    /// unambiguous, the name without its module path (see [NameIndex]).
+    fn find_by_name(name: &str) -> Option<RegistryEntry<Self>>;
+
+    /// Gets the entry registered with the given stable name, if any (see [StableNameIndex]).
+    fn find_by_stable_name(stable_name: &str) -> Option<RegistryEntry<Self>>;
+
+    /// Gets the entries registered with the given tag, in iteration order (see [TagIndex]).
+    fn with_tag(tag: &str) -> Vec<RegistryEntry<Self>>;
+
+    /// Gets the entries whose keys are exactly one segment below the given dotted prefix, in key
+    /// order (see [KeyTreeIndex]).
+    fn children(prefix: &str) -> Vec<RegistryEntry<Self>>;
+
+    /// Gets the entries whose keys are the given dotted prefix or below it, in key order (see
+    /// [KeyTreeIndex]).
+    fn subtree(prefix: &str) -> Vec<RegistryEntry<Self>>;
+
+    /// Gets the entries whose keys match the given dotted pattern, in key order (see
+    /// [KeyTree::matching](crate::KeyTree::matching)).
+    fn matching(pattern: &str) -> Vec<RegistryEntry<Self>>;
+
+    /// Gets the entries which are compatible with the given host version, i.e. whose
+    /// `requires = "..."` requirement (if any) it meets, along with a report of the entries which
+    /// were dropped (see [Compatible]).
+    fn compatible_with(host_version: Version) -> Compatible<Self>;
+
+    /// Suggests up to `max` registered keys or aliases which are similar to `query`, most similar
+    /// first. Intended for reporting failed [key lookups](RegistryExt::get), e.g.:
+    #[cfg_attr(feature = "macro", doc = "```")]
+    #[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+    /// use type_registry::{register, Registry, RegistryExt};
+    ///
+    /// #[derive(Registry)]
+    /// #[registry(type_info = ())]
+    /// struct Formats;
+    ///
+    /// #[register(Formats, key = "json")]
+    /// struct JsonFormat;
+    ///
+    /// #[register(Formats, key = "yaml", aliases = ["yml"])]
+    /// struct YamlFormat;
+    ///
+    /// fn main() {
+    ///     assert!(Formats::get("jsno").is_none());
+    ///     assert_eq!(Formats::suggest_key("jsno", 3), ["json"]);
+    ///     assert_eq!(Formats::suggest_key("ylm", 3), ["yml"]);
+    ///     assert!(Formats::suggest_key("JsonFormt", 3).is_empty());
+    ///     assert!(Formats::suggest_key("toml", 3).is_empty());
+    /// }
+    /// ```
+    fn suggest_key(query: &str, max: usize) -> Vec<&'static str>;
+
+    /// Suggests up to `max` (short) names of registered types which are similar to `query`, most
+    /// similar first. Intended for reporting failed [name lookups](RegistryExt::find_by_name),
+    /// e.g.:
+    #[cfg_attr(feature = "macro", doc = "```")]
+    #[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+    /// use type_registry::{register, Registry, RegistryExt};
+    ///
+    /// #[derive(Registry)]
+    /// #[registry(type_info = ())]
+    /// struct Formats;
+    ///
+    /// #[register(Formats, key = "json")]
+    /// struct JsonFormat;
+    ///
+    /// fn main() {
+    ///     assert!(Formats::find_by_name("JsonFormt").is_none());
+    ///     assert_eq!(Formats::suggest_name("JsonFormt", 3), ["JsonFormat"]);
+    ///     assert!(Formats::suggest_name("jsno", 3).is_empty());
+    /// }
+    /// ```
+    fn suggest_name(query: &str, max: usize) -> Vec<&'static str>;
 }
 
 impl<R: Registry + ?Sized> RegistryExt for R {
@@ -20,9 +113,17 @@ impl<R: Registry + ?Sized> RegistryExt for R {
         Iter::new()
     }
     
+    fn iter_all() -> IterAll<Self> {
+        IterAll::new()
+    }
+
+    fn iter_ordered() -> IterOrdered<Self> {
+        IterOrdered::new()
+    }
+
     fn index<I: Index<Self>>() -> &'static I::Storage {
         static STORAGE_TYPE_MAP: OnceLock<StaticTypeMap<Box<dyn Any + Send + Sync>>> = OnceLock::new();
-        let storage_type_map = STORAGE_TYPE_MAP.get_or_init(|| StaticTypeMap::new());
+        let storage_type_map = STORAGE_TYPE_MAP.get_or_init(StaticTypeMap::new);
 
         let any = storage_type_map.call_once::<(fn(R), I), _>(
             || {
@@ -41,4 +142,79 @@ impl<R: Registry + ?Sized> RegistryExt for R {
 
         any.downcast_ref().expect("index storage is associated to type")
     }
+
+    fn get(key: &str) -> Option<RegistryEntry<Self>> {
+        R::lookup(key).map(|lookup| lookup.entry())
+    }
+
+    fn lookup(key: &str) -> Option<KeyLookup<Self>> {
+        // Building the alias index checks the aliases against the keys, so is done even if the key
+        // is found, so that a collision is reported whichever key is looked up first.
+        let aliases = R::index::<AliasIndex>();
+        let lookup = match R::index::<KeyIndex>().get(key) {
+            Some(id) => KeyLookup::new(*id, None),
+            None => {
+                let (alias, id) = aliases.get_key_value(key)?;
+                KeyLookup::new(*id, Some(*alias))
+            }
+        };
+
+        report_use(lookup.registration_id());
+        Some(lookup)
+    }
+
+    fn find_by_name(name: &str) -> Option<RegistryEntry<Self>> {
+        let id = R::index::<NameIndex>().get(name)?;
+        report_use(id);
+        Some(id.entry())
+    }
+
+    fn find_by_stable_name(stable_name: &str) -> Option<RegistryEntry<Self>> {
+        let id = *R::index::<StableNameIndex>().get(stable_name)?;
+        report_use(id);
+        Some(id.entry())
+    }
+
+    fn with_tag(tag: &str) -> Vec<RegistryEntry<Self>> {
+        let Some(ids) = R::index::<TagIndex>().get(tag) else { return Vec::new() };
+        ids.iter().map(|id| id.entry()).collect()
+    }
+
+    fn children(prefix: &str) -> Vec<RegistryEntry<Self>> {
+        let Some(node) = R::index::<KeyTreeIndex>().get(prefix) else { return Vec::new() };
+        node.children()
+            .filter_map(|(_segment, child)| child.id())
+            .map(RegistrationId::entry)
+            .collect()
+    }
+
+    fn subtree(prefix: &str) -> Vec<RegistryEntry<Self>> {
+        let Some(node) = R::index::<KeyTreeIndex>().get(prefix) else { return Vec::new() };
+        node.subtree().into_iter().map(RegistrationId::entry).collect()
+    }
+
+    fn matching(pattern: &str) -> Vec<RegistryEntry<Self>> {
+        R::index::<KeyTreeIndex>().matching(pattern).into_iter().map(RegistrationId::entry).collect()
+    }
+
+    fn compatible_with(host_version: Version) -> Compatible<Self> {
+        Compatible::new(host_version)
+    }
+
+    fn suggest_key(query: &str, max: usize) -> Vec<&'static str> {
+        let keys = R::iter().flat_map(|(_id, entry)| entry.key().into_iter().chain(entry.aliases().iter().copied()));
+        suggest(query, max, keys)
+    }
+
+    fn suggest_name(query: &str, max: usize) -> Vec<&'static str> {
+        let names = R::iter().map(|(_id, entry)| NameIndexStorage::<R>::short_name(entry.type_name()));
+        suggest(query, max, names)
+    }
+}
+
+/// Notifies the [registry](Registry) if an entry which was looked up is deprecated.
+pub(crate) fn report_use<R: Registry + ?Sized>(id: RegistrationId<R>) {
+    if let Some(deprecation) = id.entry().deprecation() {
+        R::on_deprecated_use(id.entry(), deprecation);
+    }
 }
@@ -0,0 +1,76 @@
+use std::any::type_name;
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+use crate::logical::index::Index;
+use crate::logical::registration_id::RegistrationId;
+use crate::logical::registry::Registry;
+use crate::logical::registry_entry::RegistryEntry;
+
+/// [Index] of the entries in a [registry](Registry) by their stable names. The
+/// [register](crate::register) macro records the module path and name of the registered item (or
+/// the name given with `stable_name = "..."`), which unlike [std::any::type_name] doesn't depend
+/// on the compiler version, so can be persisted. Trait implementations have no stable name unless
+/// one is given. Entries without stable names are not indexed. Panics when built if two entries
+/// share a stable name.
+///
+/// E.g.:
+#[cfg_attr(feature = "macro", doc = "```")]
+#[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+/// use type_registry::{register, Registry, RegistryExt};
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = ())]
+/// struct Documents;
+///
+/// mod documents {
+///     use type_registry::register;
+///
+///     #[register(super::Documents)]
+///     pub struct Invoice;
+///
+///     #[register(super::Documents, stable_name = "receipt")]
+///     pub struct Receipt;
+///
+///     pub trait Document {}
+///
+///     #[register(super::Documents)]
+///     impl Document for String {}
+/// }
+///
+/// fn main() {
+///     let invoice = concat!(module_path!(), "::documents::Invoice");
+///     assert!(Documents::find_by_stable_name(invoice).is_some());
+///     let receipt = Documents::find_by_stable_name("receipt");
+///     assert_eq!(receipt.and_then(|entry| entry.stable_name()), Some("receipt"));
+///     let (_id, string) = Documents::iter()
+///         .find(|(_id, entry)| entry.type_name() == "alloc::string::String")
+///         .unwrap();
+///     assert_eq!(string.stable_name(), None);
+/// }
+/// ```
+pub struct StableNameIndex;
+
+impl<R: Registry + ?Sized> Index<R> for StableNameIndex {
+    type Storage = HashMap<&'static str, RegistrationId<R>>;
+
+    fn allocate() -> Self::Storage {
+        HashMap::new()
+    }
+
+    fn associate(
+        storage: &mut Self::Storage,
+        id: RegistrationId<R>,
+        entry: RegistryEntry<R>
+    ) -> ControlFlow<()> {
+        let Some(stable_name) = entry.stable_name() else { return ControlFlow::Continue(()) };
+        if let Some(existing) = storage.insert(stable_name, id) {
+            let registry = type_name::<R>();
+            let existing = existing.entry().raw().type_name();
+            let name = entry.raw().type_name();
+            panic!(
+                "duplicate '{registry}' stable name '{stable_name}' for '{existing}' and '{name}'"
+            )
+        };
+        ControlFlow::Continue(())
+    }
+}
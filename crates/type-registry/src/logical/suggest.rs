@@ -0,0 +1,52 @@
+/// Finds the candidates which are closest to `query` by [edit distance](edit_distance), closest
+/// first. Candidates which are too far from the query to be a plausible misspelling of it (more
+/// than a third of the query's length away) are excluded.
+pub(crate) fn suggest(
+    query: &str,
+    max: usize,
+    candidates: impl Iterator<Item=&'static str>
+) -> Vec<&'static str> {
+    let threshold = query.chars().count().max(3) / 3;
+
+    let mut candidates: Vec<(usize, &'static str)> = candidates
+        .map(|name| (edit_distance(query, name), name))
+        .filter(|&(distance, _)| distance <= threshold)
+        .collect();
+
+    candidates.sort();
+    candidates.dedup_by_key(|&mut (_, name)| name);
+    candidates.truncate(max);
+
+    candidates.into_iter().map(|(_, name)| name).collect()
+}
+
+/// The edit distance between two strings, i.e. the minimum number of single-character
+/// insertions, deletions, substitutions and transpositions of adjacent characters needed to turn
+/// one into the other (without editing any substring more than once).
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    // Only the previous two rows of the distance matrix need to be kept
+    let mut before_previous = vec![0; b.len() + 1];
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for i in 0..a.len() {
+        current[0] = i + 1;
+        for j in 0..b.len() {
+            let substitution = previous[j] + usize::from(a[i] != b[j]);
+            let deletion = previous[j + 1] + 1;
+            let insertion = current[j] + 1;
+            current[j + 1] = substitution.min(deletion).min(insertion);
+
+            if i > 0 && j > 0 && a[i] == b[j - 1] && a[i - 1] == b[j] {
+                current[j + 1] = current[j + 1].min(before_previous[j - 1] + 1);
+            }
+        }
+        std::mem::swap(&mut before_previous, &mut previous);
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+use crate::logical::index::Index;
+use crate::logical::registration_id::RegistrationId;
+use crate::logical::registry::Registry;
+use crate::logical::registry_entry::RegistryEntry;
+
+/// [Index] of the entries in a [registry](Registry) by the tags they were registered with. Each
+/// tag maps to its entries in iteration order.
+///
+/// E.g.:
+#[cfg_attr(feature = "macro", doc = "```")]
+#[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+/// use type_registry::{register, Registry, RegistryExt};
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = ())]
+/// struct Plugins;
+///
+/// #[register(Plugins, tags = ["image", "experimental"])]
+/// struct Denoise;
+///
+/// #[register(Plugins, tags = ["image"])]
+/// struct Resize;
+///
+/// #[register(Plugins, tags = ["network"])]
+/// struct Upload;
+///
+/// fn main() {
+///     assert_eq!(Plugins::with_tag("image").len(), 2);
+///     assert_eq!(Plugins::with_tag("experimental")[0].tags(), ["image", "experimental"]);
+///     assert!(Plugins::with_tag("audio").is_empty());
+/// }
+/// ```
+pub struct TagIndex;
+
+impl<R: Registry + ?Sized> Index<R> for TagIndex {
+    type Storage = HashMap<&'static str, Vec<RegistrationId<R>>>;
+
+    fn allocate() -> Self::Storage {
+        HashMap::new()
+    }
+
+    fn associate(
+        storage: &mut Self::Storage,
+        id: RegistrationId<R>,
+        entry: RegistryEntry<R>
+    ) -> ControlFlow<()> {
+        for tag in entry.tags() {
+            let ids = storage.entry(*tag).or_default();
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+        ControlFlow::Continue(())
+    }
+}
@@ -0,0 +1,35 @@
+use crate::logical::const_default::ConstDefault;
+use crate::TypeInfo;
+
+/// Constructs the [information](TypeInfo) about a particular registered type. This is what the
+/// [register](crate::register) macro uses to initialise a type's [type-info](crate::Registry::TypeInfo)
+/// when no initialisation expression is given.
+///
+/// The type-info is provided as an associated constant so that it can be used to initialise a
+/// static. Bounds on `T` restrict which types can be registered with registries using this
+/// type-info, e.g.:
+/// ```
+/// use type_registry::TypeInfoFor;
+///
+/// trait StringAnalyser {
+///     fn analyse(string: &str) -> Option<usize>;
+/// }
+///
+/// struct StringAnalyserTypeInfo {
+///     analyse: fn(&str) -> Option<usize>
+/// }
+///
+/// impl<T: StringAnalyser> TypeInfoFor<T> for StringAnalyserTypeInfo {
+///     const TYPE_INFO: Self = Self {
+///         analyse: T::analyse
+///     };
+/// }
+/// ```
+pub trait TypeInfoFor<T: ?Sized>: TypeInfo + Sized {
+    /// The information about `T`.
+    const TYPE_INFO: Self;
+}
+
+impl<T: ?Sized, I: TypeInfo + ConstDefault> TypeInfoFor<T> for I {
+    const TYPE_INFO: Self = I::DEFAULT;
+}
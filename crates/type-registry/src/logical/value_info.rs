@@ -0,0 +1,36 @@
+use crate::logical::registered_value::RegisteredValue;
+use crate::logical::type_info_for::TypeInfoFor;
+
+/// [Type-info](crate::Registry::TypeInfo) for registries of static values (see
+/// [ValueRegistry](crate::ValueRegistry)), holding the name of the registered static or const
+/// along with access to its value.
+pub struct ValueInfo<V: ?Sized + Sync + 'static> {
+    /// The name of the registered static or const.
+    name: &'static str,
+    /// Gets the registered value.
+    get_value: fn() -> &'static V
+}
+
+impl<V: ?Sized + Sync + 'static> ValueInfo<V> {
+    /// Creates the information about a [registered value](RegisteredValue).
+    pub const fn new<T: RegisteredValue<Value = V>>() -> Self {
+        Self {
+            name: T::NAME,
+            get_value: T::value
+        }
+    }
+
+    /// The name of the registered static or const.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Gets the registered value.
+    pub fn value(&self) -> &'static V {
+        (self.get_value)()
+    }
+}
+
+impl<V: ?Sized + Sync + 'static, T: RegisteredValue<Value = V>> TypeInfoFor<T> for ValueInfo<V> {
+    const TYPE_INFO: Self = Self::new::<T>();
+}
@@ -0,0 +1,64 @@
+use crate::logical::registration_id::RegistrationId;
+use crate::logical::registry::Registry;
+use crate::logical::value_info::ValueInfo;
+use crate::logical::values::Values;
+
+/// A [registry](Registry) of static values, rather than types. Implemented for every registry
+/// whose [type-info](Registry::TypeInfo) is [ValueInfo].
+///
+/// E.g.:
+#[cfg_attr(feature = "macro", doc = "```")]
+#[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+/// use type_registry::{register, Registry, RegistrationId, ValueInfo, ValueRegistry};
+///
+/// struct Setting {
+///     key: &'static str,
+///     default: u32
+/// }
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = ValueInfo<Setting>)]
+/// struct Settings;
+///
+/// #[register(Settings)]
+/// static RETRIES: Setting = Setting { key: "retries", default: 3 };
+///
+/// #[register(Settings)]
+/// const TIMEOUT: Setting = Setting { key: "timeout", default: 30 };
+///
+/// fn main() {
+///     let mut keys: Vec<_> = Settings::values().map(|(_id, setting)| setting.key).collect();
+///     keys.sort();
+///     assert_eq!(keys, ["retries", "timeout"]);
+///
+///     // The static itself is registered, not a copy of it
+///     let id = RegistrationId::<Settings>::of::<RETRIES>();
+///     assert!(std::ptr::eq(Settings::value(id), &RETRIES));
+/// }
+/// ```
+pub trait ValueRegistry: Registry {
+    /// The type of the registered values.
+    type Value: ?Sized + Sync + 'static;
+
+    /// Gets the value with the given registration [ID](RegistrationId).
+    fn value(id: RegistrationId<Self>) -> &'static Self::Value;
+
+    /// Iterates over the values in the registry.
+    fn values() -> Values<Self>;
+}
+
+impl<R, V> ValueRegistry for R
+where
+    R: Registry<TypeInfo = ValueInfo<V>> + ?Sized,
+    V: ?Sized + Sync + 'static
+{
+    type Value = V;
+
+    fn value(id: RegistrationId<Self>) -> &'static Self::Value {
+        id.metadata().value()
+    }
+
+    fn values() -> Values<Self> {
+        Values::new()
+    }
+}
@@ -0,0 +1,34 @@
+use crate::logical::iter::Iter;
+use crate::logical::registration_id::RegistrationId;
+use crate::logical::value_registry::ValueRegistry;
+
+/// Iterator over the values in a [value registry](ValueRegistry).
+pub struct Values<R: ValueRegistry + ?Sized> {
+    /// The iterator over the registry's entries.
+    iter: Iter<R>
+}
+
+impl<R: ValueRegistry + ?Sized> Values<R> {
+    /// Creates a new iterator over the values (and their associated [IDs](RegistrationId)) in a
+    /// [value registry](ValueRegistry).
+    pub fn new() -> Self {
+        Self {
+            iter: Iter::new()
+        }
+    }
+}
+
+impl<R: ValueRegistry + ?Sized> Default for Values<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: ValueRegistry + ?Sized> Iterator for Values<R> {
+    type Item = (RegistrationId<R>, &'static R::Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (id, _entry) = self.iter.next()?;
+        Some((id, R::value(id)))
+    }
+}
@@ -0,0 +1,71 @@
+use crate::logical::registered_variant::RegisteredVariant;
+use crate::logical::type_info_for::TypeInfoFor;
+
+/// [Type-info](crate::Registry::TypeInfo) for registries of enum variants.
+///
+/// E.g.:
+#[cfg_attr(feature = "macro", doc = "```")]
+#[cfg_attr(not(feature = "macro"), doc = "```ignore")]
+/// use type_registry::{register_variants, Registry, RegistryExt, RegistrationId, VariantInfo};
+///
+/// #[derive(Registry)]
+/// #[registry(type_info = VariantInfo)]
+/// struct CommandRegistry;
+///
+/// #[register_variants(CommandRegistry)]
+/// #[repr(u8)]
+/// enum Command {
+///     Start,
+///     Move(i32, i32),
+///     Stop { force: bool } = 10
+/// }
+///
+/// fn main() {
+///     assert_eq!(CommandRegistry::iter().count(), 3);
+///
+///     let id = RegistrationId::<CommandRegistry>::of_variant(&Command::Stop { force: true });
+///     let info = id.metadata();
+///     assert_eq!(info.name(), "Stop");
+///     assert_eq!(info.discriminant(), 10);
+///     assert_eq!(info.field_count(), 1);
+/// }
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct VariantInfo {
+    /// The name of the variant.
+    name: &'static str,
+    /// The variant's discriminant.
+    discriminant: i128,
+    /// The number of fields of the variant.
+    field_count: usize
+}
+
+impl VariantInfo {
+    /// Creates the information about a [registered variant](RegisteredVariant).
+    pub const fn new<T: RegisteredVariant>() -> Self {
+        Self {
+            name: T::NAME,
+            discriminant: T::DISCRIMINANT,
+            field_count: T::FIELD_COUNT
+        }
+    }
+
+    /// The name of the variant.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The variant's discriminant.
+    pub const fn discriminant(&self) -> i128 {
+        self.discriminant
+    }
+
+    /// The number of fields of the variant.
+    pub const fn field_count(&self) -> usize {
+        self.field_count
+    }
+}
+
+impl<T: RegisteredVariant> TypeInfoFor<T> for VariantInfo {
+    const TYPE_INFO: Self = Self::new::<T>();
+}
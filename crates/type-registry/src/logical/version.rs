@@ -0,0 +1,67 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use type_registry_version_syntax::parse_version;
+use crate::logical::parse_version_error::ParseVersionError;
+
+/// A semantic version, `major.minor.patch` (without pre-release or build metadata), e.g. of the
+/// host which registered types must be [compatible](crate::RegistryExt::compatible_with) with.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Version {
+    major: u64,
+    minor: u64,
+    patch: u64
+}
+
+impl Version {
+    /// Creates a version.
+    pub const fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self {
+            major,
+            minor,
+            patch
+        }
+    }
+
+    /// The major version.
+    pub const fn major(&self) -> u64 {
+        self.major
+    }
+
+    /// The minor version.
+    pub const fn minor(&self) -> u64 {
+        self.minor
+    }
+
+    /// The patch version.
+    pub const fn patch(&self) -> u64 {
+        self.patch
+    }
+}
+
+impl FromStr for Version {
+    type Err = ParseVersionError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (major, minor, patch) = parse_version(input).ok_or_else(|| ParseVersionError::new(input))?;
+        Ok(Self::new(major, minor, patch))
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch).cmp(&(other.major, other.minor, other.patch))
+    }
+}
+
+impl Display for Version {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{}.{}.{}", self.major, self.minor, self.patch))
+    }
+}
@@ -0,0 +1,70 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use type_registry_version_syntax::parse_requirement;
+use crate::logical::parse_version_error::ParseVersionError;
+use crate::logical::version::Version;
+
+/// A requirement on a [version](Version), as a comma-separated list of comparisons which must all
+/// hold, e.g. `>=1.4, <2`. Follows Cargo's interpretation of requirements: the operators are
+/// `>=`, `>`, `<=`, `<`, `=`, `~` and `^` (the default), versions may omit trailing parts, and
+/// `*` matches any version.
+///
+/// E.g.:
+/// ```
+/// use type_registry::{Version, VersionReq};
+///
+/// let requirement: VersionReq = ">=1.4, <2".parse().unwrap();
+/// assert!(requirement.matches(&Version::new(1, 4, 0)));
+/// assert!(requirement.matches(&Version::new(1, 9, 3)));
+/// assert!(!requirement.matches(&Version::new(2, 0, 0)));
+///
+/// let caret: VersionReq = "0.3.1".parse().unwrap();
+/// assert!(caret.matches(&Version::new(0, 3, 7)));
+/// assert!(!caret.matches(&Version::new(0, 4, 0)));
+///
+/// assert!("1.x".parse::<VersionReq>().is_err());
+/// assert!(">=18446744073709551615".parse::<VersionReq>().is_ok());
+/// assert!("^18446744073709551615".parse::<VersionReq>().is_err());
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct VersionReq {
+    /// The ranges of versions `[lower, upper)` which must all contain a matching version.
+    ranges: Vec<(Option<Version>, Option<Version>)>,
+    /// The requirement as given.
+    text: String
+}
+
+impl VersionReq {
+    /// Whether the version meets the requirement.
+    pub fn matches(&self, version: &Version) -> bool {
+        self.ranges.iter().all(|(lower, upper)| {
+            lower.is_none_or(|lower| *version >= lower) && upper.is_none_or(|upper| *version < upper)
+        })
+    }
+}
+
+impl FromStr for VersionReq {
+    type Err = ParseVersionError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let version = |(major, minor, patch)| Version::new(major, minor, patch);
+        let ranges = parse_requirement(input)
+            .ok_or_else(|| ParseVersionError::new(input))?
+            .into_iter()
+            .map(|(lower, upper)| (lower.map(version), upper.map(version)))
+            .collect();
+
+        Ok(
+            Self {
+                ranges,
+                text: input.to_owned()
+            }
+        )
+    }
+}
+
+impl Display for VersionReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.text)
+    }
+}
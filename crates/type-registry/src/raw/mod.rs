@@ -18,3 +18,7 @@ pub use registry_id::RegistryId;
 
 mod registry_info;
 pub use registry_info::RegistryInfo;
+
+mod type_ref;
+#[doc(hidden)]
+pub use type_ref::TypeRef;
@@ -1,7 +1,9 @@
 use std::any::{type_name, TypeId};
+use crate::logical::Deprecation;
 use crate::logical::Registered;
 use crate::logical::Registry;
 use crate::raw::registry_id::RegistryId;
+use crate::raw::type_ref::TypeRef;
 use crate::TypeInfo;
 
 /// The raw entry for a type registered to any [logical registry](Registry).
@@ -14,7 +16,31 @@ pub struct RegistryEntry {
     /// Gets the [TypeId] of the [registered](Registered) type.
     get_type_id: fn() -> TypeId,
     /// Gets the [name](type_name) of the [registered](Registered) type.
-    get_type_name: fn() -> &'static str
+    get_type_name: fn() -> &'static str,
+    /// The key the type was registered under, if any.
+    key: Option<&'static str>,
+    /// The explicit numeric ID the type was registered with, if any.
+    explicit_id: Option<u32>,
+    /// The stable name the type was registered with, if any.
+    stable_name: Option<&'static str>,
+    /// The priority of the registration over others with the same key.
+    priority: i32,
+    /// Whether the registration is a fallback for others with the same key.
+    weak: bool,
+    /// The types which the registered type must be ordered after.
+    after: &'static [TypeRef],
+    /// The types which the registered type must be ordered before.
+    before: &'static [TypeRef],
+    /// The tags the type was registered with.
+    tags: &'static [&'static str],
+    /// The alternative keys the type was registered under.
+    aliases: &'static [&'static str],
+    /// The deprecation of the registration, if deprecated.
+    deprecation: Option<Deprecation>,
+    /// The version the type was registered with, if any.
+    version: Option<&'static str>,
+    /// The requirement on the host version the type was registered with, if any.
+    requires: Option<&'static str>
 }
 
 impl RegistryEntry {
@@ -27,10 +53,94 @@ impl RegistryEntry {
             registry_id: RegistryId::of::<R>(),
             get_type_info: || <T as Registered<R>>::type_info(),
             get_type_id: TypeId::of::<T>,
-            get_type_name: type_name::<T>
+            get_type_name: type_name::<T>,
+            key: None,
+            explicit_id: None,
+            stable_name: None,
+            priority: 0,
+            weak: false,
+            after: &[],
+            before: &[],
+            tags: &[],
+            aliases: &[],
+            deprecation: None,
+            version: None,
+            requires: None
         }
     }
 
+    /// Sets the key the type is registered under.
+    pub const fn with_key(mut self, key: &'static str) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    /// Sets the explicit numeric ID the type is registered with.
+    pub const fn with_explicit_id(mut self, explicit_id: u32) -> Self {
+        self.explicit_id = Some(explicit_id);
+        self
+    }
+
+    /// Sets the stable name the type is registered with.
+    pub const fn with_stable_name(mut self, stable_name: &'static str) -> Self {
+        self.stable_name = Some(stable_name);
+        self
+    }
+
+    /// Sets the priority of the registration over others with the same key.
+    pub const fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Marks the registration as a fallback for others with the same key.
+    pub const fn as_weak(mut self) -> Self {
+        self.weak = true;
+        self
+    }
+
+    /// Sets the types which the registered type must be ordered after.
+    pub const fn with_after(mut self, after: &'static [TypeRef]) -> Self {
+        self.after = after;
+        self
+    }
+
+    /// Sets the types which the registered type must be ordered before.
+    pub const fn with_before(mut self, before: &'static [TypeRef]) -> Self {
+        self.before = before;
+        self
+    }
+
+    /// Sets the tags the type is registered with.
+    pub const fn with_tags(mut self, tags: &'static [&'static str]) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Sets the alternative keys the type is registered under.
+    pub const fn with_aliases(mut self, aliases: &'static [&'static str]) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    /// Marks the registration as deprecated.
+    pub const fn with_deprecation(mut self, deprecation: Deprecation) -> Self {
+        self.deprecation = Some(deprecation);
+        self
+    }
+
+    /// Sets the version the type is registered with.
+    pub const fn with_version(mut self, version: &'static str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Sets the requirement on the host version the type is registered with.
+    pub const fn with_requires(mut self, requires: &'static str) -> Self {
+        self.requires = Some(requires);
+        self
+    }
+
     /// Gets the [ID](RegistryId) of the [registry](Registry) to which the type was
     /// [registered](Registered).
     pub const fn registry_id(&self) -> RegistryId {
@@ -51,4 +161,64 @@ impl RegistryEntry {
     pub fn type_name(&self) -> &'static str {
         (self.get_type_name)()
     }
+
+    /// Gets the key the type was registered under, if any.
+    pub const fn key(&self) -> Option<&'static str> {
+        self.key
+    }
+
+    /// Gets the explicit numeric ID the type was registered with, if any.
+    pub const fn explicit_id(&self) -> Option<u32> {
+        self.explicit_id
+    }
+
+    /// Gets the stable name the type was registered with, if any.
+    pub const fn stable_name(&self) -> Option<&'static str> {
+        self.stable_name
+    }
+
+    /// Gets the priority of the registration over others with the same key.
+    pub const fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    /// Gets whether the registration is a fallback for others with the same key.
+    pub const fn is_weak(&self) -> bool {
+        self.weak
+    }
+
+    /// Gets the types which the registered type must be ordered after.
+    pub const fn after(&self) -> &'static [TypeRef] {
+        self.after
+    }
+
+    /// Gets the types which the registered type must be ordered before.
+    pub const fn before(&self) -> &'static [TypeRef] {
+        self.before
+    }
+
+    /// Gets the tags the type was registered with.
+    pub const fn tags(&self) -> &'static [&'static str] {
+        self.tags
+    }
+
+    /// Gets the alternative keys the type was registered under.
+    pub const fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    /// Gets the deprecation of the registration, if deprecated.
+    pub const fn deprecation(&self) -> Option<&Deprecation> {
+        self.deprecation.as_ref()
+    }
+
+    /// Gets the version the type was registered with, if any.
+    pub const fn version(&self) -> Option<&'static str> {
+        self.version
+    }
+
+    /// Gets the requirement on the host version the type was registered with, if any.
+    pub const fn requires(&self) -> Option<&'static str> {
+        self.requires
+    }
 }
@@ -0,0 +1,31 @@
+use std::any::{type_name, Any, TypeId};
+
+/// A reference to a type (e.g. one which a [registered](crate::logical::Registered) type must be
+/// ordered relative to), which can be created in const contexts.
+#[derive(Copy, Clone)]
+pub struct TypeRef {
+    /// Gets the [TypeId] of the referenced type.
+    get_type_id: fn() -> TypeId,
+    /// Gets the [name](type_name) of the referenced type.
+    get_type_name: fn() -> &'static str
+}
+
+impl TypeRef {
+    /// Creates a reference to the given type.
+    pub const fn of<T: Any + ?Sized>() -> Self {
+        Self {
+            get_type_id: TypeId::of::<T>,
+            get_type_name: type_name::<T>
+        }
+    }
+
+    /// Gets the [TypeId] of the referenced type.
+    pub fn type_id(&self) -> TypeId {
+        (self.get_type_id)()
+    }
+
+    /// Gets the [name](type_name) of the referenced type.
+    pub fn type_name(&self) -> &'static str {
+        (self.get_type_name)()
+    }
+}